@@ -0,0 +1,46 @@
+//! Benchmarks both parts of every day, on the puzzle samples in `tests/data/y<YEAR>/day<N>/` and on
+//! the real input when it is present.
+//!
+//! Run with `cargo bench`, or `cargo bench -- y2022::day14` for a single day. To compare a change
+//! against the current state, save a baseline first and compare against it afterwards:
+//!
+//! ```text
+//! cargo bench -- --save-baseline before
+//! cargo bench -- --baseline before
+//! ```
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use adventofrust::input::{default_samples_dir, default_inputs_dir, read_samples, InputSource};
+use adventofrust::YEARS;
+
+fn bench_days(c: &mut Criterion) {
+    for &(year, days) in YEARS {
+        for &(day, solution) in days {
+            let mut inputs = read_samples(&default_samples_dir(year), day).unwrap();
+            if let Ok(input) = InputSource::Directory(default_inputs_dir(year)).read(year, day) {
+                inputs.push(("input".to_string(), input));
+            }
+
+            let mut group = c.benchmark_group(format!("y{year}::day{day}"));
+            // some days take seconds per iteration on the real input
+            group.sample_size(10);
+            for (name, input) in &inputs {
+                for part in [1, 2] {
+                    if solution.solve(part, input).is_none() {
+                        continue;
+                    }
+                    group.bench_with_input(BenchmarkId::new(format!("part{part}"), name), input, |b, input| {
+                        b.iter(|| solution.solve(part, black_box(input)))
+                    });
+                }
+            }
+            group.finish();
+        }
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
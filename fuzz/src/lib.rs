@@ -0,0 +1,24 @@
+//! Fuzz targets for the puzzle input parsers and solutions, one per day, run from the repository
+//! root with `cargo +nightly fuzz run day9`. Each day's corpus in `corpus/day<N>` is seeded with its
+//! puzzle samples.
+//!
+//! The work of some days grows with the numbers in the input, like the steps of a day 9 motion or
+//! the width of a day 14 rock path, so a short `-timeout` finds inputs that are merely large.
+
+/// Feeds `data` to everything of the day that takes the puzzle input, all of which have to reject
+/// malformed input with an error instead of panicking. Days that stream their input have to answer
+/// the same either way.
+pub fn fuzz_day(year: u32, day: u32, data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let &(_, solution) = adventofrust::days(year).unwrap_or_default().iter().find(|&&(d, _)| d == day).unwrap();
+    let _ = solution.validate(input);
+    let _ = solution.explore(input);
+    for part in [1, 2] {
+        let answer = solution.solve(part, input);
+        if solution.streams() {
+            assert_eq!(solution.solve_lines(part, &mut &data[..]), answer, "part {part}");
+        }
+    }
+}
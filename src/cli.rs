@@ -0,0 +1,506 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::input::{self, InputSource};
+use crate::output::Format;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS: &[u32] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+
+    fn years() -> BTreeMap<u32, Vec<u32>> {
+        BTreeMap::from([(2021, vec![1, 2, 25]), (2022, DAYS.to_vec())])
+    }
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn runs_all_days_without_arguments() {
+        let command = parse_args(&[], &years()).unwrap();
+        assert_eq!(command, Command::Run(Selection { year: 2022, days: DAYS.to_vec(), ..Selection::default() }));
+    }
+
+    #[test]
+    fn parses_single_day_and_part() {
+        let command = parse_args(&args("run 9 --part 2"), &years()).unwrap();
+        assert_eq!(command, Command::Run(Selection { year: 2022, days: vec![9], part: Some(2), ..Selection::default() }));
+    }
+
+    #[test]
+    fn parses_year() {
+        let command = parse_args(&args("run 1..3 --year 2021"), &years()).unwrap();
+        assert_eq!(command, Command::Run(Selection { year: 2021, days: vec![1, 2], ..Selection::default() }));
+        let command = parse_args(&args("verify --year=2021"), &years()).unwrap();
+        assert_eq!(command, Command::Verify(Selection { year: 2021, days: vec![1, 2, 25], ..Selection::default() }));
+        assert_eq!(parse_args(&args("run 14 --year 2021"), &years()), Err("unknown day 14, available days are 1..=25".to_string()));
+        assert_eq!(parse_args(&args("run --year 2020"), &years()), Err("unknown year 2020, available years are 2021, 2022".to_string()));
+        assert!(parse_args(&args("run --year twenty"), &years()).is_err());
+    }
+
+    #[test]
+    fn parses_inclusive_range() {
+        let command = parse_args(&args("run 1..=7"), &years()).unwrap();
+        assert_eq!(command, Command::Run(Selection { year: 2022, days: (1..=7).collect(), ..Selection::default() }));
+    }
+
+    #[test]
+    fn parses_exclusive_range_and_single_days() {
+        let command = parse_args(&args("run 1..3 12 5"), &years()).unwrap();
+        assert_eq!(command, Command::Run(Selection { year: 2022, days: vec![1, 2, 5, 12], ..Selection::default() }));
+    }
+
+    #[test]
+    fn parses_all() {
+        let command = parse_args(&args("run all --part=1"), &years()).unwrap();
+        assert_eq!(command, Command::Run(Selection { year: 2022, days: DAYS.to_vec(), part: Some(1), ..Selection::default() }));
+    }
+
+    #[test]
+    fn rejects_unknown_day() {
+        assert_eq!(parse_args(&args("run 15"), &years()), Err("unknown day 15, available days are 1..=14".to_string()));
+        assert!(parse_args(&args("run 0..=3"), &years()).is_err());
+        assert!(parse_args(&args("run x"), &years()).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_part() {
+        assert_eq!(parse_args(&args("run 1 --part 3"), &years()), Err("unknown part \"3\", expected 1 or 2".to_string()));
+        assert!(parse_args(&args("run 1 --part"), &years()).is_err());
+    }
+
+    #[test]
+    fn parses_input_file() {
+        let command = parse_args(&args("run 9 --input sample.txt"), &years()).unwrap();
+        let input = Some(InputSource::File(PathBuf::from("sample.txt")));
+        assert_eq!(command, Command::Run(Selection { year: 2022, days: vec![9], input, ..Selection::default() }));
+    }
+
+    #[test]
+    fn parses_stdin_input() {
+        let command = parse_args(&args("run 9 --part=1 --input -"), &years()).unwrap();
+        assert_eq!(command, Command::Run(Selection { year: 2022, days: vec![9], part: Some(1), input: Some(InputSource::Stdin), ..Selection::default() }));
+    }
+
+    #[test]
+    fn parses_inputs_dir() {
+        let command = parse_args(&args("run --inputs-dir=/tmp/aoc 1..=3"), &years()).unwrap();
+        let input = Some(InputSource::Directory(PathBuf::from("/tmp/aoc")));
+        assert_eq!(command, Command::Run(Selection { year: 2022, days: vec![1, 2, 3], input, ..Selection::default() }));
+    }
+
+    #[test]
+    fn fetches_only_when_asked_to() {
+        let command = parse_args(&args("run 1 --fetch --inputs-dir /tmp/aoc"), &years()).unwrap();
+        let Command::Run(selection) = command else { panic!("{command:?}") };
+        assert_eq!(selection.input_source(), InputSource::Download(PathBuf::from("/tmp/aoc")));
+        let Command::Verify(selection) = parse_args(&args("verify 1"), &years()).unwrap() else { panic!() };
+        assert!(matches!(selection.input_source(), InputSource::Directory(_)));
+        assert!(parse_args(&args("run 1 --input day1.txt --fetch"), &years()).is_err());
+    }
+
+    #[test]
+    fn rejects_single_input_for_several_days() {
+        assert_eq!(parse_args(&args("run 1..=3 --input -"), &years()), Err("--input can only be used with a single day".to_string()));
+        assert!(parse_args(&args("run --input day1.txt"), &years()).is_err());
+        assert!(parse_args(&args("run 1 --input day1.txt --inputs-dir inputs"), &years()).is_err());
+    }
+
+    #[test]
+    fn parses_timing_options() {
+        let command = parse_args(&args("run 14 --time"), &years()).unwrap();
+        assert_eq!(command, Command::Run(Selection { year: 2022, days: vec![14], time: true, ..Selection::default() }));
+        let command = parse_args(&args("run 11 --slow 250"), &years()).unwrap();
+        let slow = Some(Duration::from_millis(250));
+        assert_eq!(command, Command::Run(Selection { year: 2022, days: vec![11], time: true, slow, ..Selection::default() }));
+        assert!(parse_args(&args("run --slow fast"), &years()).is_err());
+    }
+
+    #[test]
+    fn parses_jobs() {
+        let command = parse_args(&args("run --jobs 4"), &years()).unwrap();
+        assert_eq!(command, Command::Run(Selection { year: 2022, days: DAYS.to_vec(), jobs: Some(4), ..Selection::default() }));
+        let command = parse_args(&args("verify 1..=3 --jobs=2"), &years()).unwrap();
+        assert_eq!(command, Command::Verify(Selection { year: 2022, days: vec![1, 2, 3], jobs: Some(2), ..Selection::default() }));
+        assert_eq!(parse_args(&args("run --jobs 0"), &years()), Err("invalid number of jobs \"0\", expected at least 1".to_string()));
+        assert!(parse_args(&args("run --jobs many"), &years()).is_err());
+    }
+
+    #[test]
+    fn parses_format() {
+        let command = parse_args(&args("run --format json"), &years()).unwrap();
+        assert_eq!(command, Command::Run(Selection { year: 2022, days: DAYS.to_vec(), format: Format::Json, ..Selection::default() }));
+        assert!(parse_args(&args("run --format=yaml"), &years()).is_err());
+    }
+
+    #[test]
+    fn parses_verify() {
+        let command = parse_args(&args("verify 1..=3 --answers locked.toml"), &years()).unwrap();
+        let answers = Some(PathBuf::from("locked.toml"));
+        assert_eq!(command, Command::Verify(Selection { year: 2022, days: vec![1, 2, 3], answers, ..Selection::default() }));
+        assert_eq!(parse_args(&args("verify"), &years()).unwrap(), Command::Verify(Selection { year: 2022, days: DAYS.to_vec(), ..Selection::default() }));
+    }
+
+    #[test]
+    fn parses_watch() {
+        let command = parse_args(&args("watch 9 --part 2"), &years()).unwrap();
+        assert_eq!(command, Command::Watch(Selection { year: 2022, days: vec![9], part: Some(2), ..Selection::default() }));
+        assert_eq!(parse_args(&args("watch"), &years()), Err("watch needs exactly one day".to_string()));
+        assert!(parse_args(&args("watch 9 10"), &years()).is_err());
+        assert!(parse_args(&args("watch 9 --input -"), &years()).is_err());
+    }
+
+    #[test]
+    fn parses_check() {
+        let command = parse_args(&args("check 8 --input sample.txt"), &years()).unwrap();
+        let input = Some(InputSource::File(PathBuf::from("sample.txt")));
+        assert_eq!(command, Command::Check(Selection { year: 2022, days: vec![8], input, ..Selection::default() }));
+        assert_eq!(parse_args(&args("check"), &years()).unwrap(), Command::Check(Selection { year: 2022, days: DAYS.to_vec(), ..Selection::default() }));
+        assert!(parse_args(&args("check 8 --part 1"), &years()).is_err());
+    }
+
+    #[test]
+    fn parses_repl() {
+        let command = parse_args(&args("repl 14 --input sample.txt"), &years()).unwrap();
+        let input = Some(InputSource::File(PathBuf::from("sample.txt")));
+        assert_eq!(command, Command::Repl(Selection { year: 2022, days: vec![14], input, ..Selection::default() }));
+        assert_eq!(parse_args(&args("repl 1..=2"), &years()), Err("repl needs exactly one day".to_string()));
+        assert!(parse_args(&args("repl 10 --input -"), &years()).is_err());
+        assert!(parse_args(&args("repl 10 --part 1"), &years()).is_err());
+    }
+
+    #[test]
+    fn parses_new() {
+        assert_eq!(parse_args(&args("new 15"), &years()), Ok(Command::New(2022, 15)));
+        assert_eq!(parse_args(&args("new --year 2021 14"), &years()), Ok(Command::New(2021, 14)));
+        assert_eq!(parse_args(&args("new 3 --year=2023"), &years()), Ok(Command::New(2023, 3)));
+        assert_eq!(parse_args(&args("new 14"), &years()), Err("2022 day 14 already exists".to_string()));
+        assert!(parse_args(&args("new 15 --year 1999"), &years()).is_err());
+        assert!(parse_args(&args("new 26"), &years()).is_err());
+        assert!(parse_args(&args("new"), &years()).is_err());
+        assert!(parse_args(&args("new 15 16"), &years()).is_err());
+    }
+
+    #[test]
+    fn parses_submit() {
+        let command = parse_args(&args("submit 6 2 --history h.toml"), &years()).unwrap();
+        let history = Some(PathBuf::from("h.toml"));
+        assert_eq!(command, Command::Submit(Selection { year: 2022, days: vec![6], part: Some(2), history, ..Selection::default() }));
+        let command = parse_args(&args("submit 10 2 --answer=EHZFZHCZ"), &years()).unwrap();
+        let answer = Some("EHZFZHCZ".to_string());
+        assert_eq!(command, Command::Submit(Selection { year: 2022, days: vec![10], part: Some(2), answer, ..Selection::default() }));
+        assert_eq!(parse_args(&args("submit 6"), &years()), Err("submit needs a day and a part".to_string()));
+        assert!(parse_args(&args("submit 6 3"), &years()).is_err());
+        assert!(parse_args(&args("submit 6 1 2"), &years()).is_err());
+        assert!(parse_args(&args("run 6 --answer 12"), &years()).is_err());
+    }
+
+    #[test]
+    fn rejects_options_of_other_commands() {
+        assert!(parse_args(&args("run --answers locked.toml"), &years()).is_err());
+        assert!(parse_args(&args("verify --format json"), &years()).is_err());
+        assert!(parse_args(&args("watch 9 --jobs 2"), &years()).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_command() {
+        assert!(parse_args(&args("walk 1"), &years()).is_err());
+        assert!(parse_args(&args("run 1 --fast"), &years()).is_err());
+    }
+}
+
+pub const USAGE: &str = "\
+Usage: adventofrust [COMMAND]
+
+Commands:
+  run <DAYS>... [OPTIONS]     Solve the selected days (default: run all)
+  verify <DAYS>... [OPTIONS]  Solve the selected days (default: all) and compare the answers
+                              with the expected ones, exiting non-zero on a mismatch
+  watch <DAY> [OPTIONS]       Re-run the day's tests and solution whenever
+                              src/y<YEAR>/day<N>.rs or its input changes
+  submit <DAY> <PART> [OPTIONS]
+                              Solve the part and submit the answer to adventofcode.com
+                              (or $ADVENTOFRUST_BASE_URL) as the user of $ADVENTOFRUST_SESSION.
+                              Answers known to be wrong and answers during the cooldown after
+                              a wrong one are refused without asking the server
+  check <DAYS>... [OPTIONS]   Check that the inputs of the selected days (default: all) have the
+                              shape their solutions rely on, listing every problem with its line
+  repl <DAY> [OPTIONS]        Load the day's input and explore it with the day's commands, like
+                              `cycle 220` for day 10; `help` lists them
+  new <DAY> [--year <YEAR>]   Set up src/y<YEAR>/day<N>.rs with failing sample tests, an empty
+                              src/y<YEAR>/day<N>/input.txt, and register the day in
+                              src/y<YEAR>.rs, registering a new year in src/lib.rs
+  help                        Print this message
+
+DAYS is a day number, a range like 1..=7 or 1..8, or `all`.
+
+Options:
+  --year <YEAR>       Solve the days of YEAR (default: the latest year)
+  --part <1|2>        Only solve the given part
+  --input <FILE|->    Read the puzzle input from FILE, or from stdin for `-` (single day only)
+  --inputs-dir <DIR>  Read each day's input from DIR/day<N>/input.txt (default: the y<YEAR>
+                      directory in $ADVENTOFRUST_INPUTS, or in the repository's src directory).
+  --fetch             Download missing inputs into the inputs directory as the user whose
+                      session cookie of adventofcode.com, or of $ADVENTOFRUST_BASE_URL, is in
+                      $ADVENTOFRUST_SESSION
+  --time              Print how long reading and parsing the input and each part took
+  --slow <MS>         Flag days taking longer than MS milliseconds (implies --time)
+  --jobs <N>          Solve up to N days at the same time, still printing them in day order.
+                      `run` ends with how long the slowest day, the critical path, took
+  --format <FORMAT>   Print answers as text (default), json or csv. The machine-readable
+                      formats emit one {day, part, answer, elapsed_ns} record per part;
+                      multi-line answers are JSON strings with \\n escapes, or quoted CSV
+                      fields spanning several lines as in RFC 4180 (run only)
+  --answers <FILE>    Read the expected answers from FILE (verify only, default:
+                      src/y<YEAR>/answers.toml in the repository)
+  --answer <ANSWER>   Submit ANSWER instead of solving the part (submit only)
+  --history <FILE>    Keep the submitted answers and their verdicts in FILE (submit only,
+                      default: submissions.toml in the repository)";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(Selection),
+    Verify(Selection),
+    Watch(Selection),
+    Submit(Selection),
+    /// Validate the inputs of the selected days without solving them.
+    Check(Selection),
+    /// Explore a day's puzzle state with commands read from stdin.
+    Repl(Selection),
+    /// Set up a new day of a year.
+    New(u32, u32),
+    Help,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Selection {
+    pub year: u32,
+    pub days: Vec<u32>,
+    pub part: Option<u8>,
+    /// `None` to use the default inputs directory.
+    pub input: Option<InputSource>,
+    /// Whether to download missing inputs into the inputs directory.
+    pub fetch: bool,
+    pub time: bool,
+    pub slow: Option<Duration>,
+    pub format: Format,
+    /// `verify` only: `None` to use the repository's answers.toml.
+    pub answers: Option<PathBuf>,
+    /// `None` to solve one day after the other on the main thread.
+    pub jobs: Option<usize>,
+    /// `submit` only: `None` to use the repository's submissions.toml.
+    pub history: Option<PathBuf>,
+    /// `submit` only: `None` to solve the part for the answer.
+    pub answer: Option<String>,
+}
+
+impl Selection {
+    /// Where to read the selected days' inputs from, downloading missing ones only with `--fetch`.
+    pub fn input_source(&self) -> InputSource {
+        match &self.input {
+            Some(InputSource::Directory(dir)) if self.fetch => InputSource::Download(dir.clone()),
+            Some(source) => source.clone(),
+            None if self.fetch => InputSource::Download(input::default_inputs_dir(self.year)),
+            None => InputSource::Directory(input::default_inputs_dir(self.year)),
+        }
+    }
+}
+
+/// Parses the command line, given the days available in each year.
+pub fn parse_args(args: &[String], years: &BTreeMap<u32, Vec<u32>>) -> Result<Command, String> {
+    let mut args = args.iter().map(String::as_str);
+    match args.next() {
+        None => parse_selection("run", args, years).map(Command::Run),
+        Some(command @ "run") => parse_selection(command, args, years).map(Command::Run),
+        Some(command @ "verify") => parse_selection(command, args, years).map(Command::Verify),
+        Some(command @ "watch") => parse_selection(command, args, years).map(Command::Watch),
+        Some(command @ "check") => parse_selection(command, args, years).map(Command::Check),
+        Some(command @ "repl") => parse_selection(command, args, years).map(Command::Repl),
+        Some(command @ "submit") => parse_selection(command, args, years).map(Command::Submit),
+        Some("new") => parse_new(args, years),
+        Some("help" | "-h" | "--help") => Ok(Command::Help),
+        Some(command) => Err(format!("unknown command {command:?}")),
+    }
+}
+
+/// Splits `--name=value` into name and value.
+fn split_option(arg: &str) -> (&str, Option<&str>) {
+    match arg.split_once('=') {
+        Some((name, value)) if name.starts_with("--") => (name, Some(value)),
+        _ => (arg, None),
+    }
+}
+
+fn parse_selection<'a>(command: &str, mut args: impl Iterator<Item=&'a str>, years: &BTreeMap<u32, Vec<u32>>) -> Result<Selection, String> {
+    let mut selection = Selection::default();
+    let mut year = None;
+    // days can only be checked once the year is known
+    let mut day_specs = Vec::new();
+    while let Some(arg) = args.next() {
+        let (name, inline_value) = split_option(arg);
+        let mut value = || inline_value.or_else(|| args.next()).ok_or(format!("{name} requires a value"));
+        match (command, name) {
+            (_, "--year") => year = Some(parse_year(value()?)?),
+            ("run" | "verify" | "watch" | "submit", "--part") => selection.part = Some(parse_part(value()?)?),
+            ("run" | "verify", "--time") => selection.time = true,
+            ("run" | "verify", "--slow") => {
+                let millis = value()?;
+                let millis = millis.parse().map_err(|_| format!("invalid number of milliseconds {millis:?}"))?;
+                selection.slow = Some(Duration::from_millis(millis));
+                selection.time = true;
+            }
+            ("run" | "verify", "--jobs") => {
+                let jobs = value()?;
+                selection.jobs = match jobs.parse() {
+                    Ok(jobs @ 1..) => Some(jobs),
+                    _ => return Err(format!("invalid number of jobs {jobs:?}, expected at least 1")),
+                };
+            }
+            ("run", "--format") => selection.format = value()?.parse()?,
+            ("verify", "--answers") => selection.answers = Some(PathBuf::from(value()?)),
+            ("submit", "--history") => selection.history = Some(PathBuf::from(value()?)),
+            ("submit", "--answer") => selection.answer = Some(value()?.to_string()),
+            (_, "--input") => {
+                let source = match value()? {
+                    "-" => InputSource::Stdin,
+                    path => InputSource::File(PathBuf::from(path)),
+                };
+                set_input(&mut selection, source)?;
+            }
+            (_, "--inputs-dir") => set_input(&mut selection, InputSource::Directory(PathBuf::from(value()?)))?,
+            (_, "--fetch") => selection.fetch = true,
+            _ if name.starts_with('-') => return Err(format!("unknown option {arg:?} for {command}")),
+            ("submit", _) if !day_specs.is_empty() => match selection.part {
+                None => selection.part = Some(parse_part(arg)?),
+                Some(_) => return Err("submit needs a day and a part".to_string()),
+            },
+            _ => day_specs.push(arg),
+        }
+    }
+    let available;
+    (selection.year, available) = select_year(year, years)?;
+    for spec in day_specs {
+        selection.days.extend(parse_days(spec, available)?);
+    }
+    if command == "watch" || command == "repl" {
+        if selection.days.len() != 1 {
+            return Err(format!("{command} needs exactly one day"));
+        }
+        if selection.input == Some(InputSource::Stdin) {
+            return Err(format!("{command} cannot read the input from stdin"));
+        }
+    }
+    if command == "submit" && (selection.days.len() != 1 || selection.part.is_none()) {
+        return Err("submit needs a day and a part".to_string());
+    }
+    if selection.days.is_empty() {
+        selection.days.extend_from_slice(available);
+    }
+    selection.days.sort_unstable();
+    selection.days.dedup();
+    if matches!(selection.input, Some(InputSource::File(_) | InputSource::Stdin)) && selection.days.len() != 1 {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    if selection.fetch && matches!(selection.input, Some(InputSource::File(_) | InputSource::Stdin)) {
+        return Err("--fetch downloads into an inputs directory, it cannot be used with --input".to_string());
+    }
+    Ok(selection)
+}
+
+fn set_input(selection: &mut Selection, source: InputSource) -> Result<(), String> {
+    match selection.input.replace(source) {
+        Some(_) => Err("only one of --input and --inputs-dir can be given".to_string()),
+        None => Ok(()),
+    }
+}
+
+fn parse_part(value: &str) -> Result<u8, String> {
+    match value {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("unknown part {value:?}, expected 1 or 2")),
+    }
+}
+
+fn parse_days(spec: &str, available: &[u32]) -> Result<Vec<u32>, String> {
+    let (first, last) = match (available.first(), available.last()) {
+        (Some(&first), Some(&last)) => (first, last),
+        _ => return Err("no days available".to_string()),
+    };
+    let days = if spec == "all" {
+        return Ok(available.to_vec());
+    } else if let Some((start, end)) = spec.split_once("..=") {
+        parse_day(start)?..=parse_day(end)?
+    } else if let Some((start, end)) = spec.split_once("..") {
+        parse_day(start)?..=parse_day(end)?.saturating_sub(1)
+    } else {
+        let day = parse_day(spec)?;
+        day..=day
+    };
+    if days.is_empty() {
+        return Err(format!("empty day range {spec:?}"));
+    }
+    days.map(|day| {
+        if available.contains(&day) {
+            Ok(day)
+        } else {
+            Err(format!("unknown day {day}, available days are {first}..={last}"))
+        }
+    }).collect()
+}
+
+/// The given year and its days, by default the latest year.
+fn select_year(year: Option<u32>, years: &BTreeMap<u32, Vec<u32>>) -> Result<(u32, &[u32]), String> {
+    let found = match year {
+        None => years.last_key_value(),
+        Some(year) => years.get_key_value(&year),
+    };
+    match (found, year) {
+        (Some((&year, days)), _) => Ok((year, days)),
+        (None, None) => Err("no years available".to_string()),
+        (None, Some(year)) => {
+            let available: Vec<String> = years.keys().map(u32::to_string).collect();
+            Err(format!("unknown year {year}, available years are {}", available.join(", ")))
+        }
+    }
+}
+
+fn parse_year(value: &str) -> Result<u32, String> {
+    match value.parse() {
+        Ok(year @ 2015..) => Ok(year),
+        _ => Err(format!("invalid year {value:?}, Advent of Code started in 2015")),
+    }
+}
+
+/// `new <DAY> [--year <YEAR>]`, where the year does not need to exist yet.
+fn parse_new<'a>(mut args: impl Iterator<Item=&'a str>, years: &BTreeMap<u32, Vec<u32>>) -> Result<Command, String> {
+    let (mut year, mut day) = (None, None);
+    while let Some(arg) = args.next() {
+        match split_option(arg) {
+            ("--year", value) => year = Some(parse_year(value.or_else(|| args.next()).ok_or("--year requires a value")?)?),
+            (name, _) if name.starts_with('-') => return Err(format!("unknown option {arg:?} for new")),
+            _ if day.is_none() => day = Some(arg),
+            _ => return Err("new needs exactly one day".to_string()),
+        }
+    }
+    let day = day.ok_or("new needs exactly one day")?;
+    let year = year.or(years.last_key_value().map(|(&year, _)| year)).ok_or("no years available, pass --year")?;
+    let available = years.get(&year).map_or(&[][..], Vec::as_slice);
+    parse_new_day(day, year, available).map(|day| Command::New(year, day))
+}
+
+fn parse_new_day(value: &str, year: u32, available: &[u32]) -> Result<u32, String> {
+    match parse_day(value)? {
+        day if available.contains(&day) => Err(format!("{year} day {day} already exists")),
+        day @ 1..=25 => Ok(day),
+        day => Err(format!("there is no day {day}, Advent of Code has days 1 to 25")),
+    }
+}
+
+fn parse_day(value: &str) -> Result<u32, String> {
+    value.parse().map_err(|_| format!("invalid day {value:?}"))
+}
@@ -0,0 +1,126 @@
+use std::fmt::{Display, Formatter};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_part_of_line() {
+        let line = "move 1 from x to 3";
+        let error = Error::in_line(line, &line[12..13], "invalid stack number").on_line(7);
+        assert_eq!(error, Error { day: None, line: 7, column: 13, text: "x".to_string(), message: "invalid stack number".to_string() });
+    }
+
+    #[test]
+    fn counts_columns_in_characters() {
+        let line = "äö x";
+        let error = Error::in_line(line, &line[5..], "unexpected");
+        assert_eq!(error.column, 4);
+    }
+
+    #[test]
+    fn falls_back_to_first_column_for_foreign_text() {
+        let foreign = String::from("Q");
+        let error = Error::in_line("A X", &foreign, "unknown symbol");
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+
+    #[test]
+    fn moves_error_into_enclosing_line() {
+        let line = "2-4,6-x";
+        let part = &line[4..];
+        let error = Error::in_line(part, &part[2..], "invalid section").within(line, part);
+        assert_eq!((error.column, error.text.as_str()), (7, "x"));
+    }
+
+    #[test]
+    fn moves_error_into_enclosing_block() {
+        let error = Error::new("x", "invalid number").on_line(3).in_block(16);
+        assert_eq!(error.line, 18);
+    }
+
+    #[test]
+    fn displays_location() {
+        let line = "A Q";
+        let error = Error::in_line(line, &line[2..], "could not match my symbol").on_line(3);
+        assert_eq!(error.to_string(), "line 3, column 3: could not match my symbol: \"Q\"");
+        assert_eq!(error.for_day(2).to_string(), "day 2, line 3, column 3: could not match my symbol: \"Q\"");
+    }
+
+    #[test]
+    fn omits_empty_text() {
+        let error = Error::new("", "empty input");
+        assert_eq!(error.to_string(), "line 1, column 1: empty input");
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Malformed puzzle input: what was wrong, and where.
+///
+/// Parsers of a single line report line 1; the caller moves the error to the right line with
+/// [`Error::on_line`]. The runner adds the day with [`Error::for_day`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub day: Option<u32>,
+    /// 1-based line number within the input.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending part of the input.
+    pub text: String,
+    pub message: String,
+}
+
+impl Error {
+    /// An error about `text` at the very start of the input.
+    pub fn new(text: &str, message: impl Into<String>) -> Error {
+        Error { day: None, line: 1, column: 1, text: text.to_string(), message: message.into() }
+    }
+
+    /// An error about `part`, which has to be a slice of `line` to get the right column.
+    pub fn in_line(line: &str, part: &str, message: impl Into<String>) -> Error {
+        let offset = (part.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let column = if offset <= line.len() && line.is_char_boundary(offset) {
+            line[..offset].chars().count() + 1
+        } else {
+            1
+        };
+        Error { column, ..Error::new(part, message) }
+    }
+
+    /// Moves an error found while parsing `part` on its own to the position of `part` in `line`.
+    pub fn within(self, line: &str, part: &str) -> Error {
+        let offset = Error::in_line(line, part, "").column - 1;
+        Error { column: self.column + offset, ..self }
+    }
+
+    /// Moves an error found while parsing a block of lines on its own to the block's position in
+    /// the input, starting on `first_line`.
+    pub fn in_block(self, first_line: usize) -> Error {
+        Error { line: first_line + self.line - 1, ..self }
+    }
+
+    pub fn on_line(self, line: usize) -> Error {
+        Error { line, ..self }
+    }
+
+    pub fn for_day(self, day: u32) -> Error {
+        Error { day: Some(day), ..self }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        if !self.text.is_empty() {
+            write!(f, ": {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,303 @@
+use std::fs::File;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
+
+use crate::input::{day_input_path, read_file_content};
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    use super::*;
+
+    /// Answers every request with the next of `responses`, recording the requests it got.
+    #[derive(Clone, Default)]
+    pub(crate) struct FakeBackend {
+        pub(crate) responses: Arc<Mutex<Vec<io::Result<String>>>>,
+        pub(crate) requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl HttpBackend for FakeBackend {
+        fn get(&self, url: &str, _headers: &[(&str, &str)]) -> io::Result<String> {
+            self.requests.lock().unwrap().push(url.to_string());
+            self.responses.lock().unwrap().remove(0)
+        }
+
+        fn post_form(&self, url: &str, _headers: &[(&str, &str)], form: &[(&str, &str)]) -> io::Result<String> {
+            let form: Vec<_> = form.iter().map(|(name, value)| format!("{name}={value}")).collect();
+            self.requests.lock().unwrap().push(format!("{url} {}", form.join("&")));
+            self.responses.lock().unwrap().remove(0)
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("adventofrust-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Serves `responses` (status line and body) to one connection each on a local port, returning
+    /// its base URL and the received requests, head and body, once all responses have been sent.
+    pub(crate) fn stub_server(responses: Vec<(&'static str, &'static str)>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            responses.into_iter().map(|(status, body)| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                    if line.trim_end().is_empty() {
+                        break;
+                    }
+                    request += &line;
+                }
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+                request += &String::from_utf8(request_body).unwrap();
+                write!(stream, "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len()).unwrap();
+                request
+            }).collect()
+        });
+        (base_url, server)
+    }
+
+    #[test]
+    fn builds_input_url() {
+        let fetcher = Fetcher::new("http://localhost:8080/", "abc", Box::new(FakeBackend::default()));
+        assert_eq!(fetcher.input_url(2022, 9), "http://localhost:8080/2022/day/9/input");
+        assert_eq!(fetcher.answer_url(2021, 25), "http://localhost:8080/2021/day/25/answer");
+    }
+
+    #[test]
+    fn fetches_missing_input_once() {
+        let dir = temp_dir("once");
+        let backend = FakeBackend::default();
+        backend.responses.lock().unwrap().push(Ok("R 4\nU 4\n".to_string()));
+        let cache = InputCache::new(dir.clone(), Some(Fetcher::new("http://aoc.test", "abc", Box::new(backend.clone()))));
+
+        assert_eq!(cache.read(2022, 9).unwrap(), "R 4\nU 4\n");
+        assert_eq!(cache.read(2022, 9).unwrap(), "R 4\nU 4\n");
+        assert_eq!(*backend.requests.lock().unwrap(), vec!["http://aoc.test/2022/day/9/input"]);
+        assert_eq!(fs::read_to_string(day_input_path(&dir, 9)).unwrap(), "R 4\nU 4\n");
+        let files: Vec<_> = fs::read_dir(dir.join("day9")).unwrap().map(|entry| entry.unwrap().file_name()).collect();
+        assert_eq!(files, ["input.txt"]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn opens_missing_input_after_fetching_it() {
+        let dir = temp_dir("open");
+        let backend = FakeBackend::default();
+        backend.responses.lock().unwrap().push(Ok("1000\n\n2000\n".to_string()));
+        let cache = InputCache::new(dir.clone(), Some(Fetcher::new("http://aoc.test", "abc", Box::new(backend.clone()))));
+
+        let mut content = String::new();
+        cache.open(2022, 1).unwrap().read_to_string(&mut content).unwrap();
+        assert_eq!(content, "1000\n\n2000\n");
+        cache.open(2022, 1).unwrap();
+        assert_eq!(backend.requests.lock().unwrap().len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn does_not_cache_failed_downloads() {
+        let dir = temp_dir("failed");
+        let backend = FakeBackend::default();
+        backend.responses.lock().unwrap().push(Err(io::Error::other("404 Not Found")));
+        let cache = InputCache::new(dir.clone(), Some(Fetcher::new("http://aoc.test", "abc", Box::new(backend))));
+
+        assert!(cache.read(2022, 25).is_err());
+        assert!(!day_input_path(&dir, 25).exists());
+    }
+
+    #[test]
+    fn reports_missing_input_without_session() {
+        let cache = InputCache::new(temp_dir("offline"), None);
+        let error = cache.read(2022, 3).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+        assert!(error.to_string().contains(SESSION_VAR), "{error}");
+    }
+
+    #[test]
+    fn downloads_from_stub_server() {
+        let dir = temp_dir("stub");
+        let (base_url, server) = stub_server(vec![("200 OK", "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n")]);
+        let cache = InputCache::new(dir.clone(), Some(Fetcher::new(&base_url, "secret", Box::new(UreqBackend))));
+
+        assert_eq!(cache.read(2022, 6).unwrap(), "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n");
+        assert_eq!(cache.read(2022, 6).unwrap(), "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n");
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2022/day/6/input HTTP/1.1"), "{}", requests[0]);
+        assert!(requests[0].to_lowercase().contains("cookie: session=secret"), "{}", requests[0]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn submits_form_to_stub_server() {
+        let (base_url, server) = stub_server(vec![("200 OK", "<article><p>That's the right answer!</p></article>")]);
+        let body = Fetcher::new(&base_url, "secret", Box::new(UreqBackend)).submit(2022, 6, 2, "2974").unwrap();
+        assert!(body.contains("right answer"));
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2022/day/6/answer HTTP/1.1"), "{}", requests[0]);
+        assert!(requests[0].ends_with("level=2&answer=2974"), "{}", requests[0]);
+    }
+
+    #[test]
+    fn reports_http_errors_from_stub_server() {
+        let (base_url, server) = stub_server(vec![("404 Not Found", "Please don't repeatedly request this endpoint before it unlocks!")]);
+        let error = Fetcher::new(&base_url, "secret", Box::new(UreqBackend)).fetch(2022, 25).unwrap_err();
+        server.join().unwrap();
+        assert!(error.to_string().contains("404"), "{error}");
+    }
+}
+
+/// Environment variable holding the value of the `session` cookie of a logged in adventofcode.com user.
+pub const SESSION_VAR: &str = "ADVENTOFRUST_SESSION";
+
+/// Environment variable overriding where inputs are downloaded from and answers are submitted to,
+/// e.g. a local test server.
+pub const BASE_URL_VAR: &str = "ADVENTOFRUST_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Fetching the puzzle inputs is asked to identify itself to the Advent of Code team.
+const USER_AGENT: &str = "github.com/avonengel/adventofrust";
+
+/// Does the actual HTTP requests, so that tests can do without a network.
+pub trait HttpBackend: Send + Sync {
+    /// The body of a successful `GET` of `url`; any other response is an error.
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<String>;
+
+    /// The body of the response to `POST`ing `form` to `url` as `application/x-www-form-urlencoded`.
+    fn post_form(&self, url: &str, headers: &[(&str, &str)], form: &[(&str, &str)]) -> io::Result<String>;
+}
+
+/// The default backend, using `ureq`.
+pub struct UreqBackend;
+
+impl HttpBackend for UreqBackend {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<String> {
+        let mut request = ureq::get(url);
+        for &(name, value) in headers {
+            request = request.header(name, value);
+        }
+        let mut response = request.call().map_err(|e| io::Error::other(format!("GET {url} failed: {e}")))?;
+        response.body_mut().read_to_string().map_err(|e| io::Error::other(format!("GET {url} failed: {e}")))
+    }
+
+    fn post_form(&self, url: &str, headers: &[(&str, &str)], form: &[(&str, &str)]) -> io::Result<String> {
+        let mut request = ureq::post(url);
+        for &(name, value) in headers {
+            request = request.header(name, value);
+        }
+        let mut response = request.send_form(form.iter().copied()).map_err(|e| io::Error::other(format!("POST {url} failed: {e}")))?;
+        response.body_mut().read_to_string().map_err(|e| io::Error::other(format!("POST {url} failed: {e}")))
+    }
+}
+
+/// Downloads puzzle inputs and submits answers for a logged in user.
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+    backend: Box<dyn HttpBackend>,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: &str, backend: Box<dyn HttpBackend>) -> Fetcher {
+        Fetcher { base_url: base_url.trim_end_matches('/').to_string(), session: session.to_string(), backend }
+    }
+
+    /// A fetcher using `$ADVENTOFRUST_SESSION` and `$ADVENTOFRUST_BASE_URL`, `None` without a session.
+    pub fn from_env() -> Option<Fetcher> {
+        let session = env::var(SESSION_VAR).ok().filter(|session| !session.trim().is_empty())?;
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Some(Fetcher::new(&base_url, session.trim(), Box::new(UreqBackend)))
+    }
+
+    pub fn input_url(&self, year: u32, day: u32) -> String {
+        format!("{}/{year}/day/{day}/input", self.base_url)
+    }
+
+    pub fn fetch(&self, year: u32, day: u32) -> io::Result<String> {
+        let cookie = format!("session={}", self.session);
+        self.backend.get(&self.input_url(year, day), &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])
+    }
+
+    pub fn answer_url(&self, year: u32, day: u32) -> String {
+        format!("{}/{year}/day/{day}/answer", self.base_url)
+    }
+
+    /// Posts `answer` for `part` of `day`, returning the page telling whether it was right.
+    pub fn submit(&self, year: u32, day: u32, part: u8, answer: &str) -> io::Result<String> {
+        let cookie = format!("session={}", self.session);
+        let level = part.to_string();
+        self.backend.post_form(&self.answer_url(year, day), &[("Cookie", &cookie), ("User-Agent", USER_AGENT)],
+                               &[("level", &level), ("answer", answer)])
+    }
+}
+
+/// The puzzle inputs of a year in a directory of `day<N>/input.txt` files, downloading those that
+/// are missing if there is a [`Fetcher`]. A day is downloaded at most once, failed downloads are not
+/// cached.
+pub struct InputCache {
+    dir: PathBuf,
+    fetcher: Option<Fetcher>,
+}
+
+impl InputCache {
+    pub fn new(dir: PathBuf, fetcher: Option<Fetcher>) -> InputCache {
+        InputCache { dir, fetcher }
+    }
+
+    /// The input file of `day` to read bit by bit, downloaded first if missing.
+    pub fn open(&self, year: u32, day: u32) -> io::Result<File> {
+        let path = day_input_path(&self.dir, day);
+        match File::open(&path) {
+            Err(error) if error.kind() == ErrorKind::NotFound => {
+                self.read(year, day)?;
+                File::open(&path)
+            }
+            result => result,
+        }
+    }
+
+    pub fn read(&self, year: u32, day: u32) -> io::Result<String> {
+        let path = day_input_path(&self.dir, day);
+        match read_file_content(&path) {
+            Err(error) if error.kind() == ErrorKind::NotFound => {
+                let Some(fetcher) = &self.fetcher else {
+                    return Err(io::Error::new(ErrorKind::NotFound, format!("{error}; pass --fetch with {SESSION_VAR} set to download it")));
+                };
+                let content = fetcher.fetch(year, day)?;
+                write_atomically(&path, &content)?;
+                Ok(content)
+            }
+            result => result,
+        }
+    }
+}
+
+/// Writes `content` to a temporary file next to `path` and renames it into place, so that an
+/// interrupted download or another run reading `path` at the same time never sees half an input.
+fn write_atomically(path: &Path, content: &str) -> io::Result<()> {
+    let dir = path.parent().unwrap();
+    fs::create_dir_all(dir)?;
+    let temp = dir.join(format!(".{}.{}.tmp", path.file_name().unwrap().to_string_lossy(), process::id()));
+    fs::write(&temp, content).and_then(|_| fs::rename(&temp, path)).inspect_err(|_| {
+        let _ = fs::remove_file(&temp);
+    })
+}
@@ -0,0 +1,205 @@
+use std::cmp::max;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn does_arithmetic() {
+        let mut point = Point::new(3, -2) + Point::new(1, 5);
+        assert_eq!(point, Point::new(4, 3));
+        point -= Point::new(4, 0);
+        assert_eq!(point, Point::new(0, 3));
+        assert_eq!(-point * 2, Point::new(0, -6));
+        assert_eq!(Point::new(7_u32, 9) - Point::new(2, 4), Point::new(5, 5));
+    }
+
+    #[test]
+    fn measures_distances() {
+        assert_eq!(Point::new(1, 1).manhattan(Point::new(4, -3)), 7);
+        assert_eq!(Point::new(1, 1).chebyshev(Point::new(4, -3)), 4);
+        assert_eq!(Point::new(5_usize, 0).manhattan(Point::new(2, 2)), 5);
+    }
+
+    #[test]
+    fn steps_in_directions_with_y_pointing_down() {
+        assert_eq!(Direction::N.offset::<i32>(), Point::new(0, -1));
+        assert_eq!(Direction::SE.offset::<i64>(), Point::new(1, 1));
+        assert_eq!(Point::new(3_usize, 0).step(Direction::S), Some(Point::new(3, 1)));
+        assert_eq!(Point::new(3_usize, 0).step(Direction::NE), None);
+        assert_eq!(Point::new(0_usize, 4).step(Direction::W), None);
+    }
+
+    #[test]
+    fn parses_instruction_directions() {
+        assert_eq!("U".parse(), Ok(Direction::N));
+        assert_eq!("L".parse(), Ok(Direction::W));
+        assert_eq!("X".parse::<Direction>(), Err("unknown direction \"X\", expected U, D, L or R".to_string()));
+    }
+
+    #[test]
+    fn converts_coordinates() {
+        assert_eq!(Point::new(-1_i32, 2).try_cast::<usize>(), None);
+        assert_eq!(Point::new(1_i32, 2).try_cast::<usize>(), Some(Point::new(1, 2)));
+        assert_eq!(Point::new(usize::MAX, 0).try_cast::<isize>(), None);
+        assert_eq!(Point::new(498, 4).to_string(), "498,4");
+    }
+}
+
+/// A point, or the vector between two points, with `y` growing downwards like the lines of the
+/// input do.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Point<T> {
+        Point { x, y }
+    }
+
+    /// The point with both coordinates converted to `U`, `None` if one of them does not fit.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point::new(U::try_from(self.x).ok()?, U::try_from(self.y).ok()?))
+    }
+}
+
+impl<T: Copy + Ord + Add<Output=T> + Sub<Output=T>> Point<T> {
+    /// The number of steps between the points, moving orthogonally.
+    pub fn manhattan(self, other: Point<T>) -> T {
+        distance(self.x, other.x) + distance(self.y, other.y)
+    }
+
+    /// The number of steps between the points, moving diagonally as well.
+    pub fn chebyshev(self, other: Point<T>) -> T {
+        max(distance(self.x, other.x), distance(self.y, other.y))
+    }
+}
+
+fn distance<T: Ord + Sub<Output=T>>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+impl Point<usize> {
+    /// `self + vector`, `None` left of or above the origin.
+    pub fn checked_add_signed(self, vector: Point<isize>) -> Option<Point<usize>> {
+        Some(Point::new(self.x.checked_add_signed(vector.x)?, self.y.checked_add_signed(vector.y)?))
+    }
+
+    /// The next point in `direction`, `None` left of or above the origin.
+    pub fn step(self, direction: Direction) -> Option<Point<usize>> {
+        self.checked_add_signed(direction.offset())
+    }
+}
+
+impl<T: Add<Output=T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Point<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: Sub<Output=T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Point<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Neg<Output=T>> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// Scales a vector.
+impl<T: Copy + Mul<Output=T>> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, factor: T) -> Point<T> {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// Like the puzzle inputs write points, `x,y`.
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// The directions of a single step, north pointing to smaller `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    /// North, east, south and west.
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::N, Direction::NE, Direction::E, Direction::SE,
+        Direction::S, Direction::SW, Direction::W, Direction::NW,
+    ];
+
+    /// The vector of a single step.
+    pub fn offset<T: From<i8>>(self) -> Point<T> {
+        let (x, y) = match self {
+            Direction::N => (0, -1),
+            Direction::NE => (1, -1),
+            Direction::E => (1, 0),
+            Direction::SE => (1, 1),
+            Direction::S => (0, 1),
+            Direction::SW => (-1, 1),
+            Direction::W => (-1, 0),
+            Direction::NW => (-1, -1),
+        };
+        Point::new(T::from(x), T::from(y))
+    }
+}
+
+/// Parses the `U`, `D`, `L` and `R` of puzzle instructions.
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Direction, String> {
+        match s {
+            "U" => Ok(Direction::N),
+            "D" => Ok(Direction::S),
+            "L" => Ok(Direction::W),
+            "R" => Ok(Direction::E),
+            _ => Err(format!("unknown direction {s:?}, expected U, D, L or R")),
+        }
+    }
+}
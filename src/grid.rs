@@ -0,0 +1,212 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use crate::error::{Error, Result};
+use crate::geom::{Direction, Point};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    const SAMPLE_INPUT: &str = indoc! {"
+        123
+        456
+    "};
+
+    fn digits(input: &str) -> Result<Grid<u32>> {
+        Grid::parse(input, |_, chr| chr.to_digit(10).ok_or_else(|| "expected a digit".to_string()))
+    }
+
+    fn xy(points: impl Iterator<Item=Point<usize>>) -> Vec<(usize, usize)> {
+        points.map(|point| (point.x, point.y)).collect()
+    }
+
+    #[test]
+    fn parses_character_maps() {
+        let grid = digits(SAMPLE_INPUT).unwrap();
+        assert_eq!(grid.dimensions(), (2, 3));
+        assert_eq!(grid[Point::new(0, 1)], 4);
+        assert_eq!(grid.get(Point::new(2, 0)), Some(&3));
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(digits("").unwrap().dimensions(), (0, 0));
+    }
+
+    #[test]
+    fn passes_points_to_the_mapping() {
+        let grid = Grid::parse("ab\ncd", |point, _| Ok::<_, String>(point)).unwrap();
+        assert_eq!(grid[Point::new(0, 1)], Point::new(0, 1));
+    }
+
+    #[test]
+    fn reports_invalid_maps() {
+        let error = digits("123\n45\n").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (2, "expected 3 cells like in the first row"));
+        let error = digits("123\n4x6\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str(), error.message.as_str()), (2, 2, "x", "expected a digit"));
+    }
+
+    #[test]
+    fn iterates_rows_columns_and_rays() {
+        let grid = digits(SAMPLE_INPUT).unwrap();
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(grid.ray(Point::new(2, 1), Direction::W).copied().collect::<Vec<_>>(), vec![5, 4]);
+        assert_eq!(grid.ray(Point::new(0, 0), Direction::SE).copied().collect::<Vec<_>>(), vec![5]);
+        assert_eq!(grid.ray(Point::new(0, 0), Direction::N).count(), 0);
+        assert_eq!(xy(grid.points()), vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn lists_neighbours_within_the_grid() {
+        let grid = Grid::new(2, 3, 0);
+        assert_eq!(xy(grid.neighbours4(Point::new(0, 0))), vec![(1, 0), (0, 1)]);
+        assert_eq!(xy(grid.neighbours4(Point::new(1, 1))), vec![(1, 0), (2, 1), (0, 1)]);
+        assert_eq!(xy(grid.neighbours8(Point::new(1, 1))), vec![(1, 0), (2, 0), (2, 1), (0, 1), (0, 0)]);
+    }
+
+    #[test]
+    fn displays_cells_row_by_row() {
+        let mut grid = digits(SAMPLE_INPUT).unwrap();
+        grid[Point::new(1, 0)] = 0;
+        assert_eq!(grid.to_string(), "103\n456\n");
+    }
+}
+
+/// A rectangular grid of cells, stored row by row and indexed by points with `x` the column and
+/// `y` the row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    rows: usize,
+    columns: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(rows: usize, columns: usize, value: T) -> Grid<T> {
+        Grid { rows, columns, cells: vec![value; rows * columns] }
+    }
+
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a map of one character per cell and one line per row, turning each character into a
+    /// cell with `cell`, which gets the character's point along with it. Every row has to be as
+    /// long as the first one.
+    pub fn parse(input: &str, mut cell: impl FnMut(Point<usize>, char) -> std::result::Result<T, String>) -> Result<Grid<T>> {
+        let columns = input.lines().next().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        for (row, line) in input.lines().enumerate() {
+            let error = |part: &str, message: String| Error::in_line(line, part, message).on_line(row + 1);
+            if line.chars().count() != columns {
+                return Err(error(line, format!("expected {columns} cells like in the first row")));
+            }
+            for (column, (byte_idx, chr)) in line.char_indices().enumerate() {
+                let value = cell(Point::new(column, row), chr).map_err(|message| error(&line[byte_idx..byte_idx + chr.len_utf8()], message))?;
+                cells.push(value);
+            }
+            rows += 1;
+        }
+        Ok(Grid { rows, columns, cells })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// The number of rows and columns.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.columns)
+    }
+
+    pub fn contains(&self, point: Point<usize>) -> bool {
+        point.y < self.rows && point.x < self.columns
+    }
+
+    /// The cell at `point`, `None` outside the grid.
+    pub fn get(&self, point: Point<usize>) -> Option<&T> {
+        self.contains(point).then(|| &self.cells[point.y * self.columns + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point<usize>) -> Option<&mut T> {
+        self.contains(point).then(|| &mut self.cells[point.y * self.columns + point.x])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item=Point<usize>> {
+        let (rows, columns) = self.dimensions();
+        (0..rows).flat_map(move |y| (0..columns).map(move |x| Point::new(x, y)))
+    }
+
+    /// The cells of `row` from left to right. Panics if there is no such row.
+    pub fn row(&self, row: usize) -> impl Iterator<Item=&T> {
+        assert!(row < self.rows, "row {row} is outside of the {} rows", self.rows);
+        self.cells[row * self.columns..(row + 1) * self.columns].iter()
+    }
+
+    /// The cells of `column` from top to bottom. Panics if there is no such column.
+    pub fn column(&self, column: usize) -> impl Iterator<Item=&T> {
+        assert!(column < self.columns, "column {column} is outside of the {} columns", self.columns);
+        self.cells.iter().skip(column).step_by(self.columns)
+    }
+
+    /// The cells from `from`, which is left out, in `direction` up to the edge of the grid.
+    pub fn ray(&self, from: Point<usize>, direction: Direction) -> impl Iterator<Item=&T> {
+        std::iter::successors(Some(from), move |&point| self.step(point, direction))
+            .skip(1)
+            .map(|point| &self[point])
+    }
+
+    /// The points north, east, south and west of `point` that are within the grid.
+    pub fn neighbours4(&self, point: Point<usize>) -> impl Iterator<Item=Point<usize>> + '_ {
+        Direction::ORTHOGONAL.into_iter().filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// The points around `point`, clockwise from north, that are within the grid.
+    pub fn neighbours8(&self, point: Point<usize>) -> impl Iterator<Item=Point<usize>> + '_ {
+        Direction::ALL.into_iter().filter_map(move |direction| self.step(point, direction))
+    }
+
+    fn step(&self, point: Point<usize>, direction: Direction) -> Option<Point<usize>> {
+        point.step(direction).filter(|&next| self.contains(next))
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &T {
+        let (rows, columns) = self.dimensions();
+        self.get(point).unwrap_or_else(|| panic!("{point} is outside of the {columns}x{rows} grid"))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+        let (rows, columns) = self.dimensions();
+        self.get_mut(point).unwrap_or_else(|| panic!("{point} is outside of the {columns}x{rows} grid"))
+    }
+}
+
+/// Every cell as it displays, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.rows {
+            for cell in self.row(row) {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
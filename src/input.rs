@@ -0,0 +1,176 @@
+use std::{env, fmt, fs, io};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::fetch::{Fetcher, InputCache};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_day_from_directory() {
+        let dir = env::temp_dir().join(format!("adventofrust-input-{}", std::process::id()));
+        fs::create_dir_all(dir.join("day3")).unwrap();
+        fs::write(dir.join("day3").join("input.txt"), "vJrwpWtwJgWrhcsFMMfFFhFp\n").unwrap();
+
+        let source = InputSource::Directory(dir.clone());
+        assert_eq!(source.read(2022, 3).unwrap(), "vJrwpWtwJgWrhcsFMMfFFhFp\n");
+        let lines: Vec<String> = source.open(2022, 3).unwrap().lines().collect::<io::Result<_>>().unwrap();
+        assert_eq!(lines, ["vJrwpWtwJgWrhcsFMMfFFhFp"]);
+        assert!(source.open(2022, 4).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn names_day_and_path_of_missing_input() {
+        let source = InputSource::Directory(PathBuf::from("does/not/exist"));
+        let error = source.read(2022, 7).unwrap_err();
+        assert_eq!(error.day, 7);
+        assert!(error.to_string().starts_with("could not read input for day 7 from does/not/exist/day7/input.txt: "));
+    }
+
+    #[test]
+    fn finds_inputs_of_this_repository_by_default() {
+        assert!(InputSource::Directory(default_inputs_dir(2022)).read(2022, 1).is_ok());
+        assert!(default_inputs_dir(2022).ends_with("y2022"));
+    }
+
+    #[test]
+    fn reads_samples_of_this_repository() {
+        let samples = read_samples(&default_samples_dir(2022), 6).unwrap();
+        let names: Vec<&str> = samples.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["sample1", "sample2", "sample3", "sample4", "sample5"]);
+        assert_eq!(samples[0].1, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n");
+        assert!(read_samples(&default_samples_dir(2022), 99).unwrap().is_empty());
+    }
+
+    #[test]
+    fn reads_samples_in_numeric_order() {
+        let dir = env::temp_dir().join(format!("adventofrust-samples-{}", std::process::id()));
+        fs::create_dir_all(dir.join("day6")).unwrap();
+        for file in ["sample10.txt", "sample2.txt", "sample_large.txt", "sample1.txt", "sample1.expected", "notes.txt"] {
+            fs::write(dir.join("day6").join(file), file).unwrap();
+        }
+        let samples = read_samples(&dir, 6).unwrap();
+        let names: Vec<&str> = samples.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["sample1", "sample2", "sample10", "sample_large"]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
+
+/// Environment variable overriding the directory that holds the `y<YEAR>/day<N>/input.txt` files.
+pub const INPUTS_DIR_VAR: &str = "ADVENTOFRUST_INPUTS";
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A directory containing `day<N>/input.txt` for every day of a year.
+    Directory(PathBuf),
+    /// A directory like [`Directory`](InputSource::Directory), downloading missing inputs into it
+    /// as the user of `$ADVENTOFRUST_SESSION`.
+    Download(PathBuf),
+    /// A single file, only usable when solving a single day.
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn read(&self, year: u32, day: u32) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::Directory(dir) => InputCache::new(dir.clone(), None).read(year, day),
+            InputSource::Download(dir) => InputCache::new(dir.clone(), Fetcher::from_env()).read(year, day),
+            InputSource::File(path) => read_file_content(path),
+            InputSource::Stdin => {
+                let mut contents = String::new();
+                io::stdin().read_to_string(&mut contents).map(|_| contents)
+            }
+        };
+        result.map_err(|source| InputError { day, location: self.describe(day), source })
+    }
+
+    /// The input of `day` to read line by line. Stdin can only be read once.
+    pub fn open(&self, year: u32, day: u32) -> Result<Box<dyn BufRead>, InputError> {
+        let result = match self {
+            InputSource::Directory(dir) => InputCache::new(dir.clone(), None).open(year, day),
+            InputSource::Download(dir) => InputCache::new(dir.clone(), Fetcher::from_env()).open(year, day),
+            InputSource::File(path) => fs::File::open(path),
+            InputSource::Stdin => return Ok(Box::new(io::stdin().lock())),
+        };
+        result.map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+            .map_err(|source| InputError { day, location: self.describe(day), source })
+    }
+
+    fn describe(&self, day: u32) -> String {
+        match self {
+            InputSource::Directory(dir) | InputSource::Download(dir) => day_input_path(dir, day).display().to_string(),
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => "stdin".to_string(),
+        }
+    }
+}
+
+/// The `y<YEAR>` directory in `$ADVENTOFRUST_INPUTS` if set, otherwise in the `src` directory of
+/// this repository.
+pub fn default_inputs_dir(year: u32) -> PathBuf {
+    env::var_os(INPUTS_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("src"))
+        .join(format!("y{year}"))
+}
+
+pub fn day_input_path(dir: &Path, day: u32) -> PathBuf {
+    dir.join(format!("day{day}")).join("input.txt")
+}
+
+/// The `tests/data/y<YEAR>` directory of this repository, holding the puzzle samples of a year as
+/// `day<N>/sample<K>.txt` and their answers as `day<N>/sample<K>.expected`.
+pub fn default_samples_dir(year: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("data").join(format!("y{year}"))
+}
+
+/// Name and content of every `sample*.txt` file of `day`, in the order of their numbers, so that
+/// `sample2` comes before `sample10`. Samples without a number come last, sorted by name.
+pub fn read_samples(dir: &Path, day: u32) -> io::Result<Vec<(String, String)>> {
+    let day_dir = dir.join(format!("day{day}"));
+    if !day_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut samples = Vec::new();
+    for entry in fs::read_dir(day_dir)? {
+        let path = entry?.path();
+        match path.file_stem().and_then(|stem| stem.to_str()) {
+            Some(name) if name.starts_with("sample") && path.extension().is_some_and(|ext| ext == "txt") => {
+                samples.push((name.to_string(), read_file_content(&path)?));
+            }
+            _ => {}
+        }
+    }
+    samples.sort_by_cached_key(|(name, _)| (name["sample".len()..].parse::<u32>().unwrap_or(u32::MAX), name.clone()));
+    Ok(samples)
+}
+
+pub fn read_file_content(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub day: u32,
+    location: String,
+    source: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "could not read input for day {} from {}: {}", self.day, self.location, self.source)
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
@@ -0,0 +1,48 @@
+//! Solutions to [Advent of Code](https://adventofcode.com) puzzles.
+//!
+//! Every year has its own module like [`y2022`], in which every day lives in its own module and
+//! implements [`Solution`]; the year's `DAYS` lists all of them, and [`YEARS`] lists every year.
+//! Utilities shared by all years live at the crate root.
+//! The `adventofrust` binary is a thin command line front end to [`runner`].
+
+// tests live at the top of each day's module, ahead of the code they exercise
+#![allow(clippy::items_after_test_module)]
+
+pub use crate::error::{Error, Result};
+pub use crate::solution::{Answer, Solution};
+
+pub mod cli;
+pub mod error;
+pub mod fetch;
+pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod repl;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+pub mod timing;
+pub mod verify;
+pub mod watch;
+pub mod y2022;
+
+/// The solved days of a year, in order.
+pub type Days = &'static [(u32, &'static dyn Solution)];
+
+/// Every year with solutions, oldest first. Adding a year means adding its entry here.
+pub const YEARS: &[(u32, Days)] = &[
+    (2022, y2022::DAYS),
+];
+
+/// The solved days of `year`, `None` if there are none.
+pub fn days(year: u32) -> Option<Days> {
+    YEARS.iter().find(|&&(y, _)| y == year).map(|&(_, days)| days)
+}
+
+/// The year commands use unless told otherwise.
+pub fn latest_year() -> u32 {
+    YEARS.last().map_or(0, |&(year, _)| year)
+}
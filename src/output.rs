@@ -0,0 +1,145 @@
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::solution::Answer;
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record { day: 5, part: 1, answer: Answer::Text("CMZ".to_string()), elapsed: Duration::from_nanos(1_500) },
+            Record { day: 10, part: 1, answer: Answer::Number(13140), elapsed: Duration::from_micros(20) },
+            Record { day: 10, part: 2, answer: Answer::Render("#.\"\n.#\n".to_string()), elapsed: Duration::from_millis(3) },
+        ]
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("CSV".parse(), Ok(Format::Csv));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn renders_json() {
+        assert_eq!(to_json(&records()), indoc! {r##"
+            [
+              {"day": 5, "part": 1, "answer": "CMZ", "elapsed_ns": 1500},
+              {"day": 10, "part": 1, "answer": 13140, "elapsed_ns": 20000},
+              {"day": 10, "part": 2, "answer": "#.\"\n.#\n", "elapsed_ns": 3000000}
+            ]
+        "##});
+    }
+
+    #[test]
+    fn renders_empty_json() {
+        assert_eq!(to_json(&[]), "[\n]\n");
+    }
+
+    #[test]
+    fn escapes_control_characters_in_json() {
+        assert_eq!(json_string("a\\b\t\u{1}"), r#""a\\b\t\u0001""#);
+    }
+
+    #[test]
+    fn renders_csv() {
+        assert_eq!(to_csv(&records()), indoc! {r##"
+            day,part,answer,elapsed_ns
+            5,1,CMZ,1500
+            10,1,13140,20000
+            10,2,"#.""
+            .#
+            ",3000000
+        "##});
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format {s:?}, expected text, json or csv")),
+        }
+    }
+}
+
+/// One solved part, as emitted by the machine-readable formats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u32,
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// A JSON array with one object per record. Numbers stay numbers, everything else becomes a
+/// string, so multi-line renders keep their line breaks as `\n`.
+pub fn to_json(records: &[Record]) -> String {
+    let mut result = String::from("[\n");
+    for (idx, record) in records.iter().enumerate() {
+        let answer = match &record.answer {
+            Answer::Number(number) => number.to_string(),
+            Answer::Text(text) | Answer::Render(text) => json_string(text),
+        };
+        let separator = if idx + 1 < records.len() { "," } else { "" };
+        writeln!(result, "  {{\"day\": {}, \"part\": {}, \"answer\": {answer}, \"elapsed_ns\": {}}}{separator}",
+                 record.day, record.part, record.elapsed.as_nanos()).unwrap();
+    }
+    result.push_str("]\n");
+    result
+}
+
+fn json_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => write!(result, "\\u{:04x}", c as u32).unwrap(),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+/// CSV with a header row, quoted as in RFC 4180: answers containing commas, quotes or line
+/// breaks are wrapped in double quotes, so multi-line renders span several physical lines.
+pub fn to_csv(records: &[Record]) -> String {
+    let mut result = String::from("day,part,answer,elapsed_ns\n");
+    for record in records {
+        writeln!(result, "{},{},{},{}", record.day, record.part, csv_field(&record.answer.to_string()),
+                 record.elapsed.as_nanos()).unwrap();
+    }
+    result
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
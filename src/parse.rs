@@ -0,0 +1,310 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::geom::Point;
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn reads_numbers_and_tags() {
+        let parsed = line("addx -15", |s| {
+            s.expect("addx ")?;
+            s.signed::<i32>("a value")
+        });
+        assert_eq!(parsed, Ok(-15));
+        assert_eq!(line("42", |s| s.unsigned::<u8>("a number")), Ok(42));
+        let error = line("move 3 from x", |s| {
+            s.expect("move ")?;
+            s.unsigned::<u32>("a count")?;
+            s.expect(" from ")?;
+            s.unsigned::<u32>("a stack")
+        }).unwrap_err();
+        assert_eq!((error.column, error.text.as_str(), error.message.as_str()), (13, "x", "expected a stack"));
+    }
+
+    #[test]
+    fn reports_numbers_as_a_whole() {
+        let error = line("20x0", |s| s.unsigned::<u32>("calories")).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (1, "20x0"));
+        let error = line("256", |s| s.unsigned::<u8>("a byte")).unwrap_err();
+        assert_eq!(error.message, "expected a byte");
+        let error = line("-", |s| s.signed::<i32>("a value")).unwrap_err();
+        assert_eq!(error.text, "-");
+    }
+
+    #[test]
+    fn reads_ranges_points_and_lists() {
+        assert_eq!(line("2-4", |s| s.range::<u32>("a section")), Ok(2..=4));
+        assert_eq!(line("498,4 -> 498,-6", |s| s.list(" -> ", |s| s.point::<i32>())), Ok(vec![Point::new(498, 4), Point::new(498, -6)]));
+        let error = line("2-4,68", |s| s.list(",", |s| s.range::<u32>("a section"))).unwrap_err();
+        assert_eq!((error.column, error.text.as_str(), error.message.as_str()), (5, "68", "expected a range like 2-4"));
+        let error = line("503,4 -> 502;4", |s| s.list(" -> ", |s| s.point::<usize>())).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (10, "502;4"));
+    }
+
+    #[test]
+    fn reads_labelled_fields() {
+        let items = line("  Starting items: 79, 98", |s| s.field("Starting items", |s| s.list(", ", |s| s.unsigned::<u64>("an item"))));
+        assert_eq!(items, Ok(vec![79, 98]));
+        let error = line("  Starting: 79", |s| s.field("Starting items", |s| s.unsigned::<u64>("an item"))).unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (3, "expected \"Starting items\""));
+    }
+
+    #[test]
+    fn requires_whole_lines() {
+        let error = line("[4]]]", |s| s.expect("[4]")).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (4, "]]"));
+        let error = lines("1\n2\n3 4\n", |s| s.unsigned::<u32>("a number")).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (3, 2, " 4"));
+    }
+
+    #[test]
+    fn tells_what_was_consumed() {
+        let mut scanner = Scanner::new("move 13 from 2");
+        scanner.expect("move ").unwrap();
+        assert_eq!(scanner.consumed(|s| s.unsigned::<u32>("a count")), Ok(("13", 13)));
+        assert_eq!(scanner.word(), "from");
+        assert_eq!(scanner.rest(), " 2");
+    }
+
+    #[test]
+    fn splits_blocks_at_blank_lines() {
+        let input = indoc! {"
+
+            1000
+            2000
+
+            4000
+
+
+            5000
+        "};
+        assert_eq!(blocks(input).collect::<Vec<_>>(), vec![(2, "1000\n2000"), (5, "4000"), (8, "5000")]);
+
+        let mut lines = Lines::new("Monkey 0:\n  Test: divisible by x");
+        assert_eq!(lines.next_line(|s| s.expect("Monkey 0:")), Ok(()));
+        let error = lines.next_line(|s| s.field("Test", |s| {
+            s.expect("divisible by ")?;
+            s.unsigned::<u64>("a divisor")
+        })).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 22));
+        assert_eq!(lines.next_line(|s| s.expect("  If true")).unwrap_err().message, "expected another line");
+    }
+}
+
+/// Parses `line` with `parse`, which has to read all of it.
+pub fn line<'a, T>(line: &'a str, parse: impl FnOnce(&mut Scanner<'a>) -> Result<T>) -> Result<T> {
+    let mut scanner = Scanner::new(line);
+    let value = parse(&mut scanner)?;
+    scanner.end()?;
+    Ok(value)
+}
+
+/// Parses every line of `input` like [`line`] does.
+pub fn lines<'a, T>(input: &'a str, mut parse: impl FnMut(&mut Scanner<'a>) -> Result<T>) -> Result<Vec<T>> {
+    input.lines()
+        .enumerate()
+        .map(|(line_idx, text)| line(text, &mut parse).map_err(|e| e.on_line(line_idx + 1)))
+        .collect()
+}
+
+/// The blocks of `input` separated by blank lines, along with the number of their first line.
+/// Errors within a block move to the right line with [`Error::in_block`].
+pub fn blocks(input: &str) -> impl Iterator<Item=(usize, &str)> {
+    let mut lines = input.lines().enumerate().peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(|(_, line)| line.is_empty()).is_some() {}
+        let (first_idx, first) = lines.next()?;
+        let mut last = first;
+        while let Some((_, line)) = lines.next_if(|(_, line)| !line.is_empty()) {
+            last = line;
+        }
+        let offset = |line: &str| line.as_ptr() as usize - input.as_ptr() as usize;
+        Some((first_idx + 1, &input[offset(first)..offset(last) + last.len()]))
+    })
+}
+
+/// Parses the lines of a block one after another, each in its own way.
+pub struct Lines<'a> {
+    lines: std::str::Lines<'a>,
+    line_idx: usize,
+}
+
+impl<'a> Lines<'a> {
+    pub fn new(input: &'a str) -> Lines<'a> {
+        Lines { lines: input.lines(), line_idx: 0 }
+    }
+
+    /// Parses the next line like [`line`] does.
+    pub fn next_line<T>(&mut self, parse: impl FnOnce(&mut Scanner<'a>) -> Result<T>) -> Result<T> {
+        self.line_idx += 1;
+        let text = self.lines.next().ok_or_else(|| Error::new("", "expected another line").on_line(self.line_idx))?;
+        line(text, parse).map_err(|e| e.on_line(self.line_idx))
+    }
+}
+
+/// Reads a line from left to right, reporting errors at the column they occur in.
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    line: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(line: &'a str) -> Scanner<'a> {
+        Scanner { line, rest: line }
+    }
+
+    pub fn line(&self) -> &'a str {
+        self.line
+    }
+
+    /// What is left to read.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// An error about `part`, which has to be a slice of the line to get the right column.
+    pub fn error(&self, part: &str, message: impl Into<String>) -> Error {
+        Error::in_line(self.line, part, message)
+    }
+
+    /// Fails unless everything has been read.
+    pub fn end(&self) -> Result<()> {
+        if self.rest.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(self.rest, "unexpected text"))
+        }
+    }
+
+    /// Reads `tag` if the rest starts with it.
+    pub fn accept(&mut self, tag: &str) -> bool {
+        match self.rest.strip_prefix(tag) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn expect(&mut self, tag: &str) -> Result<()> {
+        if self.accept(tag) {
+            Ok(())
+        } else {
+            Err(self.error(self.token(), format!("expected {tag:?}")))
+        }
+    }
+
+    /// Reads everything that is left.
+    pub fn take_rest(&mut self) -> &'a str {
+        self.take(self.rest.len())
+    }
+
+    /// Skips whitespace, then reads up to the next whitespace. Empty at the end of the line.
+    pub fn word(&mut self) -> &'a str {
+        self.rest = self.rest.trim_start();
+        self.take(self.rest.find(char::is_whitespace).unwrap_or(self.rest.len()))
+    }
+
+    /// A number without a sign, `what` saying what it is in errors like "expected `what`".
+    pub fn unsigned<T: FromStr>(&mut self, what: &str) -> Result<T> {
+        self.number(0, what)
+    }
+
+    /// A number that may start with `-`.
+    pub fn signed<T: FromStr>(&mut self, what: &str) -> Result<T> {
+        let sign = if self.rest.starts_with('-') { 1 } else { 0 };
+        self.number(sign, what)
+    }
+
+    /// The number after a sign of `sign` bytes, blaming all letters and digits that follow for
+    /// errors rather than just the first of them.
+    fn number<T: FromStr>(&mut self, sign: usize, what: &str) -> Result<T> {
+        let digits = Scanner::new(&self.rest[sign..]).token();
+        let text = &self.rest[..sign + digits.len()];
+        match text.parse() {
+            Ok(number) if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) => {
+                self.take(text.len());
+                Ok(number)
+            }
+            _ => Err(self.error(if text.is_empty() { self.token() } else { text }, format!("expected {what}"))),
+        }
+    }
+
+    /// A range like `2-4`, including both ends.
+    pub fn range<T: FromStr>(&mut self, what: &str) -> Result<RangeInclusive<T>> {
+        let start = self.rest;
+        let from = self.unsigned(what)?;
+        self.separator(start, "-", "expected a range like 2-4")?;
+        Ok(from..=self.unsigned(what)?)
+    }
+
+    /// A point like `498,4`.
+    pub fn point<T: FromStr>(&mut self) -> Result<Point<T>> {
+        let start = self.rest;
+        let x = self.signed("an x coordinate")?;
+        self.separator(start, ",", "expected a point like 498,4")?;
+        Ok(Point::new(x, self.signed("a y coordinate")?))
+    }
+
+    /// One or more items read by `item`, separated by `separator`.
+    pub fn list<T>(&mut self, separator: &str, mut item: impl FnMut(&mut Scanner<'a>) -> Result<T>) -> Result<Vec<T>> {
+        let mut items = vec![item(self)?];
+        while self.accept(separator) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// A field like `  Test: divisible by 23`, with `value` reading what follows the colon and the
+    /// spaces after it.
+    pub fn field<T>(&mut self, label: &str, value: impl FnOnce(&mut Scanner<'a>) -> Result<T>) -> Result<T> {
+        self.rest = self.rest.trim_start();
+        self.expect(label)?;
+        self.expect(":")?;
+        self.rest = self.rest.trim_start();
+        value(self)
+    }
+
+    /// What `parse` reads, along with the text it read it from.
+    pub fn consumed<T>(&mut self, parse: impl FnOnce(&mut Scanner<'a>) -> Result<T>) -> Result<(&'a str, T)> {
+        let start = self.rest;
+        let value = parse(self)?;
+        Ok((&start[..start.len() - self.rest.len()], value))
+    }
+
+    fn take(&mut self, len: usize) -> &'a str {
+        let (taken, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        taken
+    }
+
+    /// The letters and digits ahead, or the next character if it is neither.
+    fn token(&self) -> &'a str {
+        match self.rest.find(|c: char| !c.is_alphanumeric()) {
+            Some(0) => self.rest.chars().next().map_or("", |c| &self.rest[..c.len_utf8()]),
+            Some(end) => &self.rest[..end],
+            None => self.rest,
+        }
+    }
+
+    /// Reads `separator` of what started at `start`, blaming all of it up to the next whitespace if
+    /// the separator is missing.
+    fn separator(&mut self, start: &'a str, separator: &str, message: &str) -> Result<()> {
+        if self.accept(separator) {
+            return Ok(());
+        }
+        Err(self.error(&start[..start.find(char::is_whitespace).unwrap_or(start.len())], message))
+    }
+}
@@ -0,0 +1,151 @@
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::cli::Selection;
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    struct Counter(u32);
+
+    impl Session for Counter {
+        fn commands(&self) -> &'static [(&'static str, &'static str)] {
+            &[("add [N]", "add N, 1 by default"), ("show", "the current count")]
+        }
+
+        fn execute(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+            if command == "add" {
+                self.0 += argument(args, "N", Some(1))?;
+            }
+            Ok(format!("count is {}", self.0))
+        }
+    }
+
+    fn transcript(commands: &str) -> String {
+        let mut output = Vec::new();
+        run(&mut Counter(0), "> ", commands.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn runs_commands_until_quit() {
+        assert_eq!(transcript("add\nadd 5\n\nshow\nquit\nadd\n"), "> count is 1\n> count is 6\n> > count is 6\n> ");
+        assert_eq!(transcript("add"), "> count is 1\n> \n");
+    }
+
+    #[test]
+    fn reports_bad_commands() {
+        assert_eq!(transcript("add x\nsubtract 1\nadd 1 2\n"), indoc! {r#"
+            > error: invalid N "x"
+            > error: unknown command "subtract", try help
+            > error: too many arguments, expected N
+        "#}.to_string() + "> \n");
+    }
+
+    #[test]
+    fn lists_commands() {
+        assert_eq!(transcript("help\nexit"), indoc! {"
+            > add [N]  add N, 1 by default
+            show     the current count
+            help     list the commands
+            quit     leave, as does exit or end of input
+        "}.to_string() + "> ");
+    }
+
+    #[test]
+    fn parses_arguments() {
+        assert_eq!(argument::<u32>(&[], "N", Some(1)), Ok(1));
+        assert_eq!(argument::<u32>(&["220"], "N", None), Ok(220));
+        assert_eq!(argument::<u32>(&[], "N", None), Err("missing N".to_string()));
+    }
+}
+
+/// A day's puzzle state, explored one command at a time in the REPL.
+pub trait Session {
+    /// Usage and description of every command, like `("cycle <N>", "the X register during cycle N")`.
+    fn commands(&self) -> &'static [(&'static str, &'static str)];
+
+    /// Runs one of the [`commands`](Session::commands) with its arguments, returning what to print.
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String, String>;
+}
+
+const BUILT_IN: [(&str, &str); 2] = [("help", "list the commands"), ("quit", "leave, as does exit or end of input")];
+
+/// The only argument of a command, `default` if there is none.
+pub fn argument<T: FromStr>(args: &[&str], name: &str, default: Option<T>) -> Result<T, String> {
+    match (args, default) {
+        ([], Some(default)) => Ok(default),
+        ([], None) => Err(format!("missing {name}")),
+        ([arg], _) => arg.parse().map_err(|_| format!("invalid {name} {arg:?}")),
+        _ => Err(format!("too many arguments, expected {name}")),
+    }
+}
+
+/// Loads the selected day's input and reads commands for it from stdin until the end of input.
+pub fn repl(selection: Selection) -> bool {
+    let (year, day) = (selection.year, selection.days[0]);
+    let &(_, solution) = crate::days(year).unwrap_or_default().iter().find(|&&(d, _)| d == day).unwrap();
+    let source = selection.input_source();
+    let input = match source.read(year, day) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return false;
+        }
+    };
+    let mut session = match solution.explore(&input) {
+        None => {
+            eprintln!("error: day {day} has no REPL commands yet");
+            return false;
+        }
+        Some(Err(error)) => {
+            eprintln!("error: {}", error.for_day(day));
+            return false;
+        }
+        Some(Ok(session)) => session,
+    };
+    println!("Exploring {year} day {day}, type help for the commands");
+    match run(session.as_mut(), &format!("day{day}> "), io::stdin().lock(), io::stdout()) {
+        Ok(()) => true,
+        Err(error) => {
+            eprintln!("error: {error}");
+            false
+        }
+    }
+}
+
+/// Prints `prompt`, then runs the command read from `input`, until `quit` or the end of input.
+pub fn run(session: &mut dyn Session, prompt: &str, mut input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    loop {
+        write!(output, "{prompt}")?;
+        output.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            // leave the shell's prompt on a line of its own
+            return writeln!(output);
+        }
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&command, args)) = words.split_first() else {
+            continue;
+        };
+        let known = |commands: &[(&str, &str)]| commands.iter().any(|(usage, _)| usage.split(' ').next() == Some(command));
+        let result = match command {
+            "quit" | "exit" => return Ok(()),
+            "help" => {
+                let commands: Vec<_> = session.commands().iter().chain(&BUILT_IN).collect();
+                let width = commands.iter().map(|(usage, _)| usage.len()).max().unwrap_or(0);
+                let lines: Vec<_> = commands.iter().map(|(usage, description)| format!("{usage:width$}  {description}")).collect();
+                Ok(lines.join("\n"))
+            }
+            _ if known(session.commands()) => session.execute(command, args),
+            _ => Err(format!("unknown command {command:?}, try help")),
+        };
+        match result {
+            Ok(text) => writeln!(output, "{}", text.trim_end())?,
+            Err(message) => writeln!(output, "error: {message}")?,
+        }
+    }
+}
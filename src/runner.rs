@@ -0,0 +1,308 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+use crate::cli::Selection;
+use crate::error::{Error, Result};
+use crate::input::{InputError, InputSource};
+use crate::output::{Format, Record};
+use crate::solution::{Answer, Solution};
+use crate::timing::DayTiming;
+use crate::verify::ExpectedAnswers;
+use crate::{input, output, timing, verify};
+
+fn selected_days(selection: &Selection) -> impl Iterator<Item=&(u32, &'static dyn Solution)> {
+    crate::days(selection.year).unwrap_or_default().iter().filter(|(day, _)| selection.days.contains(day))
+}
+
+fn selected_parts(selection: &Selection) -> impl Iterator<Item=u8> + '_ {
+    [1, 2].into_iter().filter(|&part| selection.part.is_none_or(|p| p == part))
+}
+
+/// A selected part and its answer, `None` if the day has no such part yet.
+type PartAnswer = (u8, Option<Result<Answer>>);
+
+/// Why a day could not be solved at all.
+enum Unsolved {
+    Input(InputError),
+    /// Every violation of the input shape the day's solution relies on.
+    Invalid(Vec<Error>),
+}
+
+impl Unsolved {
+    fn report(self, day: u32) {
+        match self {
+            Unsolved::Input(error) => eprintln!("error: {error}"),
+            Unsolved::Invalid(violations) => {
+                for violation in violations {
+                    eprintln!("error: {}", violation.for_day(day));
+                }
+            }
+        }
+    }
+}
+
+/// The outcome of solving one day: its timing, and each selected part's answer unless the input
+/// could not be read or is invalid.
+struct SolvedDay {
+    day: u32,
+    timing: DayTiming,
+    parts: std::result::Result<Vec<PartAnswer>, Unsolved>,
+}
+
+fn solve_day(day: u32, solution: &dyn Solution, selection: &Selection, source: &InputSource) -> SolvedDay {
+    // stdin can only be read once, so it is read whole for all parts
+    if solution.streams() && *source != InputSource::Stdin {
+        return stream_day(day, solution, selection, source);
+    }
+    let mut timing = DayTiming::new(day);
+    let (input, elapsed) = timing::measure(|| source.read(selection.year, day));
+    timing.input = elapsed;
+    let parts = input.map_err(Unsolved::Input).and_then(|input| {
+        match solution.validate(&input) {
+            Some(violations) if !violations.is_empty() => return Err(Unsolved::Invalid(violations)),
+            _ => {}
+        }
+        // failing to parse is up to the parts to report
+        let (parsed, elapsed) = timing::measure(|| solution.parse(&input));
+        timing.parse = parsed.map(|_| elapsed);
+        Ok(selected_parts(selection).map(|part| {
+            let (answer, elapsed) = timing::measure(|| solution.solve(part, &input));
+            if answer.is_some() {
+                timing.parts[part as usize - 1] = Some(elapsed);
+            }
+            (part, answer)
+        }).collect())
+    });
+    SolvedDay { day, timing, parts }
+}
+
+/// Solves a day whose solution streams its input, reading the input once for every part. There is
+/// no input to validate as a whole, see [`Solution::streams`].
+fn stream_day(day: u32, solution: &dyn Solution, selection: &Selection, source: &InputSource) -> SolvedDay {
+    let mut timing = DayTiming::new(day);
+    let mut parts = Vec::new();
+    for part in selected_parts(selection) {
+        let (input, elapsed) = timing::measure(|| source.open(selection.year, day));
+        timing.input += elapsed;
+        let mut input = match input {
+            Ok(input) => input,
+            Err(error) => return SolvedDay { day, timing, parts: Err(Unsolved::Input(error)) },
+        };
+        let (answer, elapsed) = timing::measure(|| solution.solve_lines(part, &mut input));
+        if answer.is_some() {
+            timing.parts[part as usize - 1] = Some(elapsed);
+        }
+        parts.push((part, answer));
+    }
+    SolvedDay { day, timing, parts: Ok(parts) }
+}
+
+/// Solves the selected days, on `selection.jobs` threads if given, handing each day to `handle` in
+/// day order as soon as it and all days before it are done. Returns the number of threads used,
+/// never more than there are days.
+fn solve_days(selection: &Selection, source: &InputSource, mut handle: impl FnMut(SolvedDay)) -> usize {
+    let days: Vec<_> = selected_days(selection).collect();
+    let jobs = selection.jobs.unwrap_or(1).min(days.len());
+    if jobs <= 1 {
+        for &(day, solution) in days {
+            handle(solve_day(day, solution, selection, source));
+        }
+        return jobs;
+    }
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let sender = sender.clone();
+            let (days, next) = (&days, &next);
+            scope.spawn(move || {
+                while let Some(&&(day, solution)) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    if sender.send((day, solve_day(day, solution, selection, source))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+        // days finished ahead of an earlier one wait here until it is done
+        let mut finished = BTreeMap::new();
+        let mut pending = days.iter().map(|&&(day, _)| day);
+        let mut waiting_for = pending.next();
+        for (day, solved) in receiver {
+            finished.insert(day, solved);
+            while let Some(solved) = waiting_for.and_then(|day| finished.remove(&day)) {
+                handle(solved);
+                waiting_for = pending.next();
+            }
+        }
+    });
+    jobs
+}
+
+/// Solves the selected days and prints the answers, returning `false` if anything failed.
+pub fn run(selection: Selection) -> bool {
+    let source = selection.input_source();
+    let text = selection.format == Format::Text;
+    if text {
+        println!("Advent, Advent, ein Lichtlein brennt:");
+    }
+    let mut failed = false;
+    let mut timings = Vec::new();
+    let mut records = Vec::new();
+    let (jobs, wall_clock) = timing::measure(|| solve_days(&selection, &source, |solved| {
+        let day = solved.day;
+        if text {
+            println!("\nDay{day}:");
+        }
+        let parts = match solved.parts {
+            Ok(parts) => parts,
+            Err(unsolved) => {
+                unsolved.report(day);
+                failed = true;
+                return;
+            }
+        };
+        for (part, answer) in parts {
+            match answer {
+                Some(Err(error)) => {
+                    eprintln!("error: {}", error.for_day(day));
+                    failed = true;
+                }
+                Some(Ok(answer)) => {
+                    match &answer {
+                        _ if !text => {}
+                        Answer::Render(screen) => println!("  Part {part}: \n{screen}"),
+                        answer => println!("  Part {part}: {answer}"),
+                    }
+                    let elapsed = solved.timing.parts[part as usize - 1].unwrap_or_default();
+                    records.push(Record { day, part, answer, elapsed });
+                }
+                // only complain about a missing part if it was asked for explicitly
+                None if selection.part.is_some() => {
+                    eprintln!("error: day {day} has no part {part}");
+                    failed = true;
+                }
+                None => {}
+            }
+        }
+        timings.push(solved.timing);
+    }));
+    match selection.format {
+        Format::Text => {}
+        Format::Json => print!("{}", output::to_json(&records)),
+        Format::Csv => print!("{}", output::to_csv(&records)),
+    }
+    let mut report = String::new();
+    if selection.time {
+        report += &format!("\nTimings:\n{}", timing::report(&timings, selection.slow));
+    }
+    if selection.jobs.is_some() {
+        report += &format!("\n{}", timing::critical_path(&timings, wall_clock, jobs));
+    }
+    // keep stdout parseable for the machine-readable formats
+    if text {
+        print!("{report}");
+    } else {
+        eprint!("{report}");
+    }
+    !failed
+}
+
+/// The year's `answers.toml`, next to the inputs in this repository.
+pub fn default_answers_path(year: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join(format!("y{year}")).join("answers.toml")
+}
+
+/// Solves the selected days and compares the answers with the expected ones, returning `false`
+/// on any mismatch.
+pub fn verify(selection: Selection) -> bool {
+    let answers_path = selection.answers.clone()
+        .unwrap_or_else(|| default_answers_path(selection.year));
+    let expected = match input::read_file_content(&answers_path).map_err(|e| e.to_string())
+        .and_then(|content| ExpectedAnswers::parse(&content)) {
+        Ok(expected) => expected,
+        Err(error) => {
+            eprintln!("error: could not read expected answers from {}: {error}", answers_path.display());
+            return false;
+        }
+    };
+    let source = selection.input_source();
+    let (mut matched, mut mismatched, mut unchecked) = (0, 0, 0);
+    let mut failed = false;
+    solve_days(&selection, &source, |solved| {
+        let day = solved.day;
+        let parts = match solved.parts {
+            Ok(parts) => parts,
+            Err(unsolved) => {
+                unsolved.report(day);
+                failed = true;
+                return;
+            }
+        };
+        for (part, answer) in parts {
+            match (answer, expected.get(day, part)) {
+                (None, None) => {}
+                (None, Some(_)) => {
+                    println!("Day{day} part {part}: MISSING, there is an expected answer but no solution");
+                    mismatched += 1;
+                }
+                (Some(Err(error)), _) => {
+                    println!("Day{day} part {part}: FAILED, {}", error.for_day(day));
+                    mismatched += 1;
+                }
+                (Some(Ok(_)), None) => {
+                    println!("Day{day} part {part}: no expected answer");
+                    unchecked += 1;
+                }
+                (Some(Ok(answer)), Some(expected)) => match verify::compare(expected, &answer) {
+                    None => {
+                        println!("Day{day} part {part}: ok");
+                        matched += 1;
+                    }
+                    Some(diff) => {
+                        println!("Day{day} part {part}: MISMATCH");
+                        for line in diff.lines() {
+                            println!("    {line}");
+                        }
+                        mismatched += 1;
+                    }
+                },
+            }
+        }
+    });
+    println!("\n{matched} ok, {mismatched} mismatched, {unchecked} without expected answer");
+    !failed && mismatched == 0
+}
+
+/// Checks the selected days' inputs for the shape their solutions rely on without solving them,
+/// printing every violation and returning `false` if there was any.
+pub fn check(selection: Selection) -> bool {
+    let source = selection.input_source();
+    let mut failed = false;
+    for &(day, solution) in selected_days(&selection) {
+        let input = match source.read(selection.year, day) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("error: {error}");
+                failed = true;
+                continue;
+            }
+        };
+        match solution.validate(&input) {
+            None => println!("Day{day}: nothing to check"),
+            Some(violations) if violations.is_empty() => println!("Day{day}: ok"),
+            Some(violations) => {
+                println!("Day{day}: {} problem{}", violations.len(), if violations.len() == 1 { "" } else { "s" });
+                for violation in violations {
+                    println!("  {violation}");
+                }
+                failed = true;
+            }
+        }
+    }
+    !failed
+}
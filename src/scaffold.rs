@@ -0,0 +1,269 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::input;
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    const LIB: &str = indoc! {"
+        pub mod verify;
+        pub mod y2021;
+
+        pub const YEARS: &[(u32, Days)] = &[
+            (2021, y2021::DAYS),
+        ];
+    "};
+
+    const YEAR: &str = indoc! {"
+        use crate::Days;
+
+        pub mod day1;
+        pub mod day11;
+        pub mod day10;
+
+        pub const DAYS: Days = &[
+            (1, &day1::Day1),
+            (10, &day10::Day10),
+            (11, &day11::Day11),
+        ];
+    "};
+
+    #[test]
+    fn fills_in_the_day() {
+        let module = day_module(15);
+        assert!(module.contains("pub struct Day15;\n\nimpl Solution for Day15 {"));
+        assert!(!module.contains("{day}"));
+    }
+
+    #[test]
+    fn registers_module_and_solution() {
+        assert_eq!(register_day(YEAR, 2021, 15), Ok(indoc! {"
+            use crate::Days;
+
+            pub mod day1;
+            pub mod day11;
+            pub mod day10;
+            pub mod day15;
+
+            pub const DAYS: Days = &[
+                (1, &day1::Day1),
+                (10, &day10::Day10),
+                (11, &day11::Day11),
+                (15, &day15::Day15),
+            ];
+        "}.to_string()));
+    }
+
+    #[test]
+    fn refuses_to_register_twice() {
+        assert_eq!(register_day(YEAR, 2021, 11), Err("day 11 is already registered in src/y2021.rs".to_string()));
+        assert_eq!(register_year(LIB, 2021), Err("year 2021 is already registered in src/lib.rs".to_string()));
+    }
+
+    #[test]
+    fn registers_year() {
+        assert_eq!(register_year(LIB, 2022), Ok(indoc! {"
+            pub mod verify;
+            pub mod y2021;
+            pub mod y2022;
+
+            pub const YEARS: &[(u32, Days)] = &[
+                (2021, y2021::DAYS),
+                (2022, y2022::DAYS),
+            ];
+        "}.to_string()));
+        let module = year_module(2022, 3);
+        assert!(module.contains("pub mod day3;\n\n"));
+        assert!(module.contains("pub const DAYS: Days = &[\n    (3, &day3::Day3),\n];"));
+    }
+
+    #[test]
+    fn creates_day_in_a_repository() {
+        let root = std::env::temp_dir().join(format!("adventofrust-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join("src/y2021.rs"), YEAR).unwrap();
+
+        let written = create_day(&root, 2021, 15).unwrap();
+        assert_eq!(written, vec![root.join("src/y2021/day15.rs"), root.join("src/y2021/day15/input.txt"), root.join("src/y2021.rs")]);
+        assert_eq!(fs::read_to_string(root.join("src/y2021/day15.rs")).unwrap(), day_module(15));
+        assert!(fs::read_to_string(root.join("src/y2021.rs")).unwrap().contains("(15, &day15::Day15),"));
+        assert!(create_day(&root, 2021, 15).is_err());
+
+        let written = create_day(&root, 2022, 1).unwrap();
+        assert_eq!(written, vec![root.join("src/y2022/day1.rs"), root.join("src/y2022/day1/input.txt"), root.join("src/y2022.rs"), root.join("src/lib.rs")]);
+        assert_eq!(fs::read_to_string(root.join("src/y2022.rs")).unwrap(), year_module(2022, 1));
+        assert!(fs::read_to_string(root.join("src/lib.rs")).unwrap().contains("(2022, y2022::DAYS),"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
+
+const DAY_TEMPLATE: &str = r#"use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    // TODO paste the sample from the puzzle description
+    const SAMPLE_INPUT: &str = indoc! {"
+    "};
+
+    // TODO replace the expected values with the sample answers from the puzzle description
+    #[test]
+    fn solves_part1_sample() {
+        assert_eq!(super::part1(SAMPLE_INPUT), Ok(0));
+    }
+
+    #[test]
+    fn solves_part2_sample() {
+        assert_eq!(super::part2(SAMPLE_INPUT), Ok(0));
+    }
+}
+
+pub fn part1(_input: &str) -> Result<u64> {
+    Err(Error::new("", "day {day} part 1 is not solved yet"))
+}
+
+pub fn part2(_input: &str) -> Result<u64> {
+    Err(Error::new("", "day {day} part 2 is not solved yet"))
+}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        Some(part2(input).map(Answer::from))
+    }
+}
+"#;
+
+const YEAR_TEMPLATE: &str = r#"//! The [Advent of Code {year}](https://adventofcode.com/{year}) puzzles, with each day's input in
+//! `src/y{year}/day<N>/input.txt`.
+
+use crate::Days;
+
+pub mod day{day};
+
+/// Every solved day, in order. Adding a day means adding its entry here.
+pub const DAYS: Days = &[
+    ({day}, &day{day}::Day{day}),
+];
+"#;
+
+/// Sets up a new day in this repository and prints what was written, returning `false` on failure.
+pub fn scaffold(year: u32, day: u32) -> bool {
+    match create_day(Path::new(env!("CARGO_MANIFEST_DIR")), year, day) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path.display());
+            }
+            println!("\nPaste the puzzle input into src/y{year}/day{day}/input.txt and the sample into the tests of src/y{year}/day{day}.rs.");
+            true
+        }
+        Err(message) => {
+            eprintln!("error: {message}");
+            false
+        }
+    }
+}
+
+/// Writes `src/y<YEAR>/day<N>.rs` from the template, an empty `src/y<YEAR>/day<N>/input.txt`, and
+/// registers the day in `src/y<YEAR>.rs`, returning the files written. A year without a module yet
+/// gets one, registered in `src/lib.rs`. Existing files are never overwritten.
+pub fn create_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    let src = root.join("src");
+    let year_dir = src.join(format!("y{year}"));
+    let module_path = year_dir.join(format!("day{day}.rs"));
+    let input_path = input::day_input_path(&year_dir, day);
+    let year_path = src.join(format!("y{year}.rs"));
+    let lib_path = src.join("lib.rs");
+    if module_path.exists() {
+        return Err(format!("{} already exists", module_path.display()));
+    }
+    let read = |path: &Path| fs::read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()));
+    let (year_module, lib) = if year_path.exists() {
+        (register_day(&read(&year_path)?, year, day)?, None)
+    } else {
+        (year_module(year, day), Some(register_year(&read(&lib_path)?, year)?))
+    };
+
+    let write = |path: &Path, content: &str| fs::write(path, content).map_err(|e| format!("could not write {}: {e}", path.display()));
+    let mut written = Vec::new();
+    fs::create_dir_all(&year_dir).map_err(|e| format!("could not create {}: {e}", year_dir.display()))?;
+    write(&module_path, &day_module(day))?;
+    written.push(module_path);
+    if !input_path.exists() {
+        fs::create_dir_all(input_path.parent().unwrap()).map_err(|e| format!("could not create the directory for {}: {e}", input_path.display()))?;
+        write(&input_path, "")?;
+        written.push(input_path);
+    }
+    write(&year_path, &year_module)?;
+    written.push(year_path);
+    if let Some(lib) = lib {
+        write(&lib_path, &lib)?;
+        written.push(lib_path);
+    }
+    Ok(written)
+}
+
+/// The source of a new day's module, with failing tests and parts that are not solved yet.
+pub fn day_module(day: u32) -> String {
+    DAY_TEMPLATE.replace("{day}", &day.to_string())
+}
+
+/// The source of a new year's module, with `day` as its only day.
+pub fn year_module(year: u32, day: u32) -> String {
+    YEAR_TEMPLATE.replace("{year}", &year.to_string()).replace("{day}", &day.to_string())
+}
+
+/// Adds `pub mod day<N>;` after the last day module of a year and the day's entry at the end of
+/// its `DAYS`.
+fn register_day(year_module: &str, year: u32, day: u32) -> Result<String, String> {
+    register(year_module, &format!("src/y{year}.rs"), &format!("day {day}"), &format!("day{day}"), "pub const DAYS", &format!("({day}, &day{day}::Day{day}),"))
+}
+
+/// Adds `pub mod y<YEAR>;` after the last year module and the year's entry at the end of `YEARS`.
+fn register_year(lib: &str, year: u32) -> Result<String, String> {
+    register(lib, "src/lib.rs", &format!("year {year}"), &format!("y{year}"), "pub const YEARS", &format!("({year}, y{year}::DAYS),"))
+}
+
+/// Adds `pub mod <module>;` after the last module named like it, a prefix followed by a number,
+/// and `entry` at the end of the list starting with `list`.
+fn register(source: &str, file: &str, what: &str, module: &str, list: &str, entry: &str) -> Result<String, String> {
+    let declaration = format!("pub mod {module};");
+    if source.lines().any(|line| line == declaration) {
+        return Err(format!("{what} is already registered in {file}"));
+    }
+    let prefix = module.trim_end_matches(|c: char| c.is_ascii_digit());
+    let lines: Vec<&str> = source.lines().collect();
+    let last_module = lines.iter()
+        .rposition(|line| line.strip_prefix("pub mod ").and_then(|rest| rest.strip_prefix(prefix)?.strip_suffix(';')).is_some_and(|n| n.parse::<u32>().is_ok()))
+        .ok_or(format!("found no {prefix}<N> modules in {file}"))?;
+    let list_start = lines.iter().position(|line| line.starts_with(list)).ok_or(format!("found no {list} in {file}"))?;
+    let list_end = list_start + lines[list_start..].iter().position(|line| *line == "];").ok_or(format!("found no end of {list} in {file}"))?;
+
+    let entry = format!("    {entry}");
+    let mut result = String::new();
+    for (idx, line) in lines.iter().enumerate() {
+        if idx == list_end {
+            result += &entry;
+            result.push('\n');
+        }
+        result += line;
+        result.push('\n');
+        if idx == last_module {
+            result += &declaration;
+            result.push('\n');
+        }
+    }
+    Ok(result)
+}
@@ -0,0 +1,111 @@
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+
+use crate::error::{Error, Result};
+use crate::repl::Session;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_numbers() {
+        assert_eq!(Answer::from(42_u32), Answer::Number(42));
+        assert_eq!(Answer::from(-3_i32), Answer::Number(-3));
+        assert_eq!(Answer::from(u64::MAX), Answer::Number(u64::MAX as i128));
+    }
+
+    #[test]
+    fn displays_answers() {
+        assert_eq!(Answer::from(24_000_u32).to_string(), "24000");
+        assert_eq!(Answer::from("CMZ".to_string()).to_string(), "CMZ");
+        assert_eq!(Answer::Render("#.\n.#\n".to_string()).to_string(), "#.\n.#\n");
+    }
+}
+
+/// The answer to one part of a day's puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// A multi-line picture that has to be read by a human, like the day 10 CRT screen.
+    Render(String),
+}
+
+/// A day's puzzle. Both parts get the raw puzzle input and fail on malformed input.
+pub trait Solution: Sync {
+    fn part1(&self, input: &str) -> Result<Answer>;
+
+    /// `None` as long as part 2 has not been solved.
+    fn part2(&self, _input: &str) -> Option<Result<Answer>> {
+        None
+    }
+
+    /// The puzzle state for `input`, to explore in the REPL. `None` for days without REPL commands.
+    fn explore(&self, _input: &str) -> Option<Result<Box<dyn Session>>> {
+        None
+    }
+
+    /// Every way in which `input` lacks the shape the solution relies on, checked before solving.
+    /// `None` for days without such checks.
+    fn validate(&self, _input: &str) -> Option<Vec<Error>> {
+        None
+    }
+
+    /// Parses `input` like the parts do, without solving anything, so that parsing can be timed
+    /// on its own. The parts still parse the input for themselves. `None` for days that parse
+    /// while solving.
+    fn parse(&self, _input: &str) -> Option<Result<()>> {
+        None
+    }
+
+    fn solve(&self, part: u8, input: &str) -> Option<Result<Answer>> {
+        match part {
+            1 => Some(self.part1(input)),
+            2 => self.part2(input),
+            _ => None,
+        }
+    }
+
+    /// Whether [`solve_lines`](Solution::solve_lines) solves every part, so the runner can stream
+    /// the input instead of reading all of it at once. Streamed input is never passed to
+    /// [`validate`](Solution::validate), so days with a validator must not stream.
+    fn streams(&self) -> bool {
+        false
+    }
+
+    /// Solves `part` like [`solve`](Solution::solve), but reading `input` line by line in memory
+    /// that does not grow with the input. `None` for days that need all of the input at once.
+    fn solve_lines(&self, _part: u8, _input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        None
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) | Answer::Render(text) => f.write_str(text),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(u32, i32, u64, i64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
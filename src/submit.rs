@@ -0,0 +1,405 @@
+use std::fmt::{Display, Formatter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fs, io};
+
+use toml::{Table, Value};
+
+use crate::cli::Selection;
+use crate::fetch::{Fetcher, SESSION_VAR};
+use crate::solution::Answer;
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::fetch::tests::{stub_server, FakeBackend};
+    use crate::fetch::UreqBackend;
+
+    use super::*;
+
+    const NOW: u64 = 1_670_000_000;
+
+    fn submission(answer: &str, verdict: Verdict, wait_until: Option<u64>) -> Submission {
+        Submission { year: 2022, day: 1, part: 1, answer: answer.to_string(), verdict, at: NOW - 100, wait_until }
+    }
+
+    #[test]
+    fn parses_responses() {
+        let response = |body: &str| Response::parse(&format!("<main><article><p>{body}</p></article></main>"));
+        assert_eq!(response("That's the right answer! You are one gold star closer."), Response { verdict: Verdict::Correct, wait: None });
+        assert_eq!(response("That's not the right answer; your answer is too high.  Please wait one minute before trying again."),
+                   Response { verdict: Verdict::TooHigh, wait: Some(Duration::from_secs(60)) });
+        assert_eq!(response("That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again."),
+                   Response { verdict: Verdict::TooLow, wait: Some(Duration::from_secs(300)) });
+        assert_eq!(response("That's not the right answer.  Please wait one minute before trying again.").verdict, Verdict::Wrong);
+        assert_eq!(response("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 30s left to wait."),
+                   Response { verdict: Verdict::TooSoon, wait: Some(Duration::from_secs(90)) });
+        assert_eq!(response("You don't seem to be solving the right level.  Did you already complete it?").verdict, Verdict::AlreadySolved);
+        assert_eq!(response("Something else entirely").verdict, Verdict::Unknown);
+    }
+
+    #[test]
+    fn reads_and_writes_history() {
+        let history = History { submissions: vec![submission("24000", Verdict::TooHigh, Some(NOW - 40)), submission("12", Verdict::Correct, None)] };
+        let toml = history.to_toml();
+        assert_eq!(toml, indoc! {r#"
+            [[submission]]
+            year = 2022
+            day = 1
+            part = 1
+            answer = "24000"
+            verdict = "too high"
+            at = 1669999900
+            wait_until = 1669999960
+
+            [[submission]]
+            year = 2022
+            day = 1
+            part = 1
+            answer = "12"
+            verdict = "correct"
+            at = 1669999900
+        "#});
+        assert_eq!(History::parse(&toml).unwrap(), history);
+        assert_eq!(History::parse("").unwrap(), History::default());
+        assert!(History::parse("[[submission]]\nyear = 2022\nday = 1").is_err());
+        assert!(History::parse("[[submission]]\nyear = \"2022\"\nday = 1\npart = 1\nanswer = \"12\"\nverdict = \"correct\"\nat = 1").is_err());
+    }
+
+    #[test]
+    fn reads_history_without_years() {
+        let history = History::parse(indoc! {r#"
+            [[submission]]
+            day = 1
+            part = 1
+            answer = "24000"
+            verdict = "too high"
+            at = 1669999900
+            wait_until = 1669999960
+        "#}).unwrap();
+        assert_eq!(history, History { submissions: vec![submission("24000", Verdict::TooHigh, Some(NOW - 40))] });
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let history = History {
+            submissions: vec![submission("500", Verdict::TooHigh, None), submission("100", Verdict::TooLow, None), submission("CMZ", Verdict::Wrong, None)],
+        };
+        assert_eq!(history.check(2022, 1, 1, "250", NOW), Ok(()));
+        assert_eq!(history.check(2022, 1, 1, "500", NOW), Err("500 was already submitted for 2022 day 1 part 1, it is too high".to_string()));
+        assert_eq!(history.check(2022, 1, 1, "501", NOW), Err("501 is too high, 500 already was".to_string()));
+        assert_eq!(history.check(2022, 1, 1, "99", NOW), Err("99 is too low, 100 already was".to_string()));
+        assert!(history.check(2022, 1, 1, "CMZ", NOW).is_err());
+        assert_eq!(history.check(2022, 1, 2, "500", NOW), Ok(()));
+        assert_eq!(history.check(2021, 1, 1, "500", NOW), Ok(()));
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let history = History { submissions: vec![submission("24000", Verdict::Correct, None)] };
+        assert_eq!(history.check(2022, 1, 1, "24001", NOW), Err("2022 day 1 part 1 is already solved, the answer is 24000".to_string()));
+    }
+
+    #[test]
+    fn enforces_cooldown() {
+        let history = History { submissions: vec![submission("24000", Verdict::TooHigh, Some(NOW + 30))] };
+        assert_eq!(history.check(2022, 2, 1, "15", NOW), Err("too soon after the last answer, wait another 30s".to_string()));
+        assert_eq!(history.check(2022, 2, 1, "15", NOW + 30), Ok(()));
+    }
+
+    #[test]
+    fn records_submissions() {
+        let backend = FakeBackend::default();
+        backend.responses.lock().unwrap().push(Ok("<p>That's not the right answer; your answer is too low.  Please wait one minute before trying again.</p>".to_string()));
+        let fetcher = Fetcher::new("http://aoc.test", "abc", Box::new(backend.clone()));
+        let mut history = History::default();
+
+        let response = submit_answer(&fetcher, &mut history, 2022, 1, 2, "45000", NOW).unwrap();
+        assert_eq!(response.verdict, Verdict::TooLow);
+        assert_eq!(*backend.requests.lock().unwrap(), vec!["http://aoc.test/2022/day/1/answer level=2&answer=45000"]);
+        assert_eq!(history.submissions, vec![Submission { year: 2022, day: 1, part: 2, answer: "45000".to_string(), verdict: Verdict::TooLow, at: NOW, wait_until: Some(NOW + 60) }]);
+        // neither the same answer nor anything during the cooldown reaches the server
+        assert!(submit_answer(&fetcher, &mut history, 2022, 1, 2, "45000", NOW + 90).is_err());
+        assert!(submit_answer(&fetcher, &mut history, 2022, 1, 2, "45001", NOW + 10).is_err());
+        assert_eq!(backend.requests.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn submits_to_mock_endpoint() {
+        let (base_url, server) = stub_server(vec![("200 OK", "<article><p>That's the right answer!</p></article>")]);
+        let fetcher = Fetcher::new(&base_url, "secret", Box::new(UreqBackend));
+        let mut history = History::default();
+
+        assert_eq!(submit_answer(&fetcher, &mut history, 2022, 6, 1, "1760", NOW).unwrap().verdict, Verdict::Correct);
+        assert!(server.join().unwrap()[0].ends_with("level=1&answer=1760"));
+        assert!(submit_answer(&fetcher, &mut history, 2022, 6, 1, "1760", NOW + 60).is_err());
+    }
+}
+
+/// What adventofcode.com made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Submitted before the cooldown of the previous answer ran out, so it was not checked.
+    TooSoon,
+    AlreadySolved,
+    /// A response that could not be understood.
+    Unknown,
+}
+
+impl Verdict {
+    const ALL: [Verdict; 7] = [Verdict::Correct, Verdict::TooHigh, Verdict::TooLow, Verdict::Wrong, Verdict::TooSoon, Verdict::AlreadySolved, Verdict::Unknown];
+
+    fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooSoon => "too soon",
+            Verdict::AlreadySolved => "already solved",
+            Verdict::Unknown => "unknown",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// How long to wait before submitting the next answer.
+    pub wait: Option<Duration>,
+}
+
+impl Response {
+    /// Reads the verdict and the cooldown from the page returned for a submitted answer.
+    pub fn parse(body: &str) -> Response {
+        let verdict = if body.contains("That's the right answer") {
+            Verdict::Correct
+        } else if body.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if body.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if body.contains("You gave an answer too recently") {
+            Verdict::TooSoon
+        } else if body.contains("Did you already complete it") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        };
+        let wait = between(body, "You have ", " left to wait")
+            .or_else(|| between(body, "Please wait ", " before trying again"))
+            .and_then(parse_wait);
+        Response { verdict, wait }
+    }
+}
+
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (_, rest) = text.split_once(start)?;
+    rest.split_once(end).map(|(between, _)| between)
+}
+
+/// Parses waits like `1m 30s`, `45s`, `one minute` and `5 minutes`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    let mut words = text.split_whitespace().peekable();
+    while let Some(word) = words.next() {
+        let (number, unit) = match word.find(|c: char| !c.is_ascii_digit()) {
+            Some(0) if word == "one" => (1, words.next()?),
+            Some(0) => return None,
+            Some(idx) => (word[..idx].parse::<u64>().ok()?, &word[idx..]),
+            None => (word.parse::<u64>().ok()?, words.next()?),
+        };
+        seconds += number * match unit.trim_end_matches('.') {
+            "s" | "second" | "seconds" => 1,
+            "m" | "minute" | "minutes" => 60,
+            "h" | "hour" | "hours" => 3600,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// An answer that was submitted, with times in seconds since the Unix epoch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub at: u64,
+    /// No answer can be submitted before this time.
+    pub wait_until: Option<u64>,
+}
+
+/// The year of submissions without one.
+const LEGACY_YEAR: u32 = 2022;
+
+/// Every answer submitted so far, kept in a TOML file with one `[[submission]]` table each.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct History {
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn parse(input: &str) -> Result<History, String> {
+        let table: Table = input.parse().map_err(|e| format!("invalid history file: {e}"))?;
+        let entries = match table.get("submission") {
+            None => return Ok(History::default()),
+            Some(Value::Array(entries)) => entries,
+            Some(_) => return Err("submission must be an array of tables".to_string()),
+        };
+        let submissions = entries.iter().enumerate().map(|(idx, entry)| {
+            let error = |field: &str| format!("submission {}: missing or invalid {field}", idx + 1);
+            let integer = |field: &str| entry.get(field).and_then(Value::as_integer).ok_or_else(|| error(field));
+            let string = |field: &str| entry.get(field).and_then(Value::as_str).ok_or_else(|| error(field));
+            let verdict = string("verdict")?;
+            Ok(Submission {
+                // histories written before there were several years only have 2022 submissions
+                year: match entry.get("year") {
+                    None => LEGACY_YEAR,
+                    Some(_) => integer("year")?.try_into().map_err(|_| error("year"))?,
+                },
+                day: integer("day")?.try_into().map_err(|_| error("day"))?,
+                part: integer("part")?.try_into().map_err(|_| error("part"))?,
+                answer: string("answer")?.to_string(),
+                verdict: Verdict::ALL.into_iter().find(|v| v.to_string() == verdict).ok_or_else(|| error("verdict"))?,
+                at: integer("at")?.try_into().map_err(|_| error("at"))?,
+                wait_until: entry.get("wait_until").map(|_| integer("wait_until")?.try_into().map_err(|_| error("wait_until"))).transpose()?,
+            })
+        }).collect::<Result<_, String>>()?;
+        Ok(History { submissions })
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut result = String::new();
+        for (idx, submission) in self.submissions.iter().enumerate() {
+            if idx > 0 {
+                result.push('\n');
+            }
+            result += "[[submission]]\n";
+            writeln!(result, "year = {}\nday = {}\npart = {}", submission.year, submission.day, submission.part).unwrap();
+            writeln!(result, "answer = {}", Value::String(submission.answer.clone())).unwrap();
+            writeln!(result, "verdict = \"{}\"\nat = {}", submission.verdict, submission.at).unwrap();
+            if let Some(wait_until) = submission.wait_until {
+                writeln!(result, "wait_until = {wait_until}").unwrap();
+            }
+        }
+        result
+    }
+
+    /// Why `answer` should not be submitted for `part` of `day` in `year` at `now`, if there is a
+    /// reason.
+    pub fn check(&self, year: u32, day: u32, part: u8, answer: &str, now: u64) -> Result<(), String> {
+        let earlier = || self.submissions.iter().filter(|s| (s.year, s.day, s.part) == (year, day, part));
+        if let Some(solved) = earlier().find(|s| matches!(s.verdict, Verdict::Correct | Verdict::AlreadySolved)) {
+            return Err(match solved.verdict {
+                Verdict::Correct => format!("{year} day {day} part {part} is already solved, the answer is {}", solved.answer),
+                _ => format!("{year} day {day} part {part} is already solved"),
+            });
+        }
+        if let Some(wait_until) = self.submissions.iter().filter_map(|s| s.wait_until).max().filter(|&until| until > now) {
+            return Err(format!("too soon after the last answer, wait another {}s", wait_until - now));
+        }
+        if let Some(wrong) = earlier().find(|s| s.answer == answer && s.verdict.is_wrong()) {
+            return Err(format!("{answer} was already submitted for {year} day {day} part {part}, it is {}", wrong.verdict));
+        }
+        if let Ok(number) = answer.parse::<i128>() {
+            for submitted in earlier() {
+                let Ok(bound) = submitted.answer.parse::<i128>() else { continue };
+                match submitted.verdict {
+                    Verdict::TooHigh if number >= bound => return Err(format!("{answer} is too high, {bound} already was")),
+                    Verdict::TooLow if number <= bound => return Err(format!("{answer} is too low, {bound} already was")),
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submits `answer` unless `history` knows better, and records the response in `history`.
+pub fn submit_answer(fetcher: &Fetcher, history: &mut History, year: u32, day: u32, part: u8, answer: &str, now: u64) -> Result<Response, String> {
+    history.check(year, day, part, answer, now)?;
+    let body = fetcher.submit(year, day, part, answer).map_err(|e| format!("could not submit the answer: {e}"))?;
+    let response = Response::parse(&body);
+    history.submissions.push(Submission {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict: response.verdict,
+        at: now,
+        wait_until: response.wait.map(|wait| now + wait.as_secs()),
+    });
+    Ok(response)
+}
+
+/// The repository's `submissions.toml`.
+pub fn default_history_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("submissions.toml")
+}
+
+/// Solves the selected part of the selected day, unless an answer was given, and submits the
+/// answer. Returns `true` only if it was correct.
+pub fn submit(selection: Selection) -> bool {
+    match try_submit(&selection) {
+        Ok(response) => {
+            match response.wait {
+                Some(wait) => println!("{}, wait {}s before the next answer", response.verdict, wait.as_secs()),
+                None => println!("{}", response.verdict),
+            }
+            response.verdict == Verdict::Correct
+        }
+        Err(message) => {
+            eprintln!("error: {message}");
+            false
+        }
+    }
+}
+
+fn try_submit(selection: &Selection) -> Result<Response, String> {
+    let (year, day, part) = (selection.year, selection.days[0], selection.part.unwrap());
+    let answer = match &selection.answer {
+        Some(answer) => answer.clone(),
+        None => solve(selection)?,
+    };
+    println!("Submitting {answer} for {year} day {day} part {part}");
+    let fetcher = Fetcher::from_env().ok_or(format!("set {SESSION_VAR} to the session cookie of adventofcode.com to submit answers"))?;
+    let path = selection.history.clone().unwrap_or_else(default_history_path);
+    let mut history = match fs::read_to_string(&path) {
+        Ok(content) => History::parse(&content).map_err(|e| format!("{}: {e}", path.display()))?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => History::default(),
+        Err(e) => return Err(format!("could not read {}: {e}", path.display())),
+    };
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let response = submit_answer(&fetcher, &mut history, year, day, part, &answer, now)?;
+    fs::write(&path, history.to_toml()).map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    Ok(response)
+}
+
+fn solve(selection: &Selection) -> Result<String, String> {
+    let (year, day, part) = (selection.year, selection.days[0], selection.part.unwrap());
+    let &(_, solution) = crate::days(year).unwrap_or_default().iter().find(|&&(d, _)| d == day).unwrap();
+    let source = selection.input_source();
+    let input = source.read(year, day).map_err(|e| e.to_string())?;
+    match solution.solve(part, &input) {
+        None => Err(format!("day {day} has no part {part}")),
+        Some(Err(error)) => Err(error.for_day(day).to_string()),
+        Some(Ok(Answer::Render(_))) => Err("the answer has to be read off the screen, pass it with --answer".to_string()),
+        Some(Ok(answer)) => Ok(answer.to_string()),
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    fn timing(day: u32, millis: [u64; 4]) -> DayTiming {
+        DayTiming {
+            day,
+            input: Duration::from_millis(millis[0]),
+            parse: Some(Duration::from_millis(millis[1])),
+            parts: [Some(Duration::from_millis(millis[2])), Some(Duration::from_millis(millis[3]))],
+        }
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(Duration::from_nanos(1_500)), "1µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.500ms");
+        assert_eq!(format_duration(Duration::from_millis(2_345)), "2.345s");
+    }
+
+    #[test]
+    fn renders_report_with_total() {
+        let timings = [timing(1, [1, 4, 2, 3]), timing(14, [0, 1, 4000, 0])];
+        assert_eq!(report(&timings, None), indoc! {"
+            Day      Input      Parse     Part 1     Part 2      Total
+              1    1.000ms    4.000ms    2.000ms    3.000ms   10.000ms
+             14        0µs    1.000ms     4.000s        0µs     4.001s
+            Total  1.000ms    5.000ms     4.002s    3.000ms     4.011s
+        "});
+    }
+
+    #[test]
+    fn flags_slow_days() {
+        let timings = [timing(1, [1, 0, 2, 3]), timing(14, [0, 0, 4000, 0]), timing(2, [0, 0, 0, 0])];
+        let report = report(&timings, Some(Duration::from_millis(5)));
+        assert!(report.lines().nth(1).unwrap().ends_with("6.000ms  SLOW"));
+        assert!(report.lines().nth(2).unwrap().ends_with("4.000s  SLOW"));
+        assert!(!report.lines().nth(3).unwrap().contains("SLOW"));
+        assert!(report.ends_with("2 of 3 days took longer than 5.000ms\n"));
+    }
+
+    #[test]
+    fn summarizes_critical_path() {
+        let timings = [timing(1, [1, 0, 2, 3]), timing(14, [0, 0, 4000, 0]), timing(11, [0, 0, 300, 700])];
+        assert_eq!(critical_path(&timings, Duration::from_millis(4_100), 4), indoc! {"
+            Solved 3 days in 4.100s on 4 jobs, 5.006s of work in total
+            Critical path: day 14, 4.000s
+        "});
+        assert!(critical_path(&timings[1..2], Duration::from_millis(4_000), 1).starts_with("Solved 1 day in 4.000s on 1 job,"));
+    }
+
+    #[test]
+    fn leaves_parts_that_did_not_run_empty() {
+        let timings = [DayTiming { day: 14, input: Duration::ZERO, parse: None, parts: [Some(Duration::ZERO), None] }];
+        assert_eq!(report(&timings, None).lines().nth(1).unwrap(), " 14        0µs          -        0µs          -        0µs");
+    }
+}
+
+/// Wall-clock time spent on a single day: reading its input, parsing it and solving each part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayTiming {
+    pub day: u32,
+    pub input: Duration,
+    /// `None` for days that only parse while solving, see
+    /// [`Solution::parse`](crate::solution::Solution::parse).
+    pub parse: Option<Duration>,
+    pub parts: [Option<Duration>; 2],
+}
+
+impl DayTiming {
+    pub fn new(day: u32) -> DayTiming {
+        DayTiming { day, input: Duration::ZERO, parse: None, parts: [None, None] }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.input + self.parse.unwrap_or_default() + self.parts.iter().flatten().sum::<Duration>()
+    }
+}
+
+/// Runs `f`, returning its result together with the time it took.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Renders a table with one row per day and a total row, flagging days slower than `slow`.
+pub fn report(timings: &[DayTiming], slow: Option<Duration>) -> String {
+    let mut result = String::new();
+    let optional = |duration: Option<Duration>| duration.map_or("-".to_string(), format_duration);
+    writeln!(result, "Day  {:>9}  {:>9}  {:>9}  {:>9}  {:>9}", "Input", "Parse", "Part 1", "Part 2", "Total").unwrap();
+    for timing in timings {
+        let parts = timing.parts.map(optional);
+        write!(result, "{:>3}  {:>9}  {:>9}  {:>9}  {:>9}  {:>9}",
+               timing.day, format_duration(timing.input), optional(timing.parse), parts[0], parts[1],
+               format_duration(timing.total())).unwrap();
+        if slow.is_some_and(|slow| timing.total() > slow) {
+            result.push_str("  SLOW");
+        }
+        result.push('\n');
+    }
+    let input = timings.iter().map(|t| t.input).sum();
+    let parse = timings.iter().filter_map(|t| t.parse).sum();
+    let part = |idx: usize| timings.iter().filter_map(|t| t.parts[idx]).sum();
+    let total = timings.iter().map(DayTiming::total).sum();
+    writeln!(result, "Total{:>9}  {:>9}  {:>9}  {:>9}  {:>9}", format_duration(input), format_duration(parse),
+             format_duration(part(0)), format_duration(part(1)), format_duration(total)).unwrap();
+    if let Some(slow) = slow {
+        let slow_days = timings.iter().filter(|t| t.total() > slow).count();
+        if slow_days > 0 {
+            writeln!(result, "{slow_days} of {} days took longer than {}", timings.len(), format_duration(slow)).unwrap();
+        }
+    }
+    result
+}
+
+/// Summarizes a run on `jobs` threads. Days are independent of each other, so the run can never
+/// take less time than its slowest day, which is the critical path.
+pub fn critical_path(timings: &[DayTiming], wall_clock: Duration, jobs: usize) -> String {
+    let mut result = String::new();
+    let work: Duration = timings.iter().map(DayTiming::total).sum();
+    writeln!(result, "Solved {} in {} on {}, {} of work in total",
+             count(timings.len(), "day"), format_duration(wall_clock), count(jobs, "job"), format_duration(work)).unwrap();
+    if let Some(slowest) = timings.iter().max_by_key(|t| t.total()) {
+        writeln!(result, "Critical path: day {}, {}", slowest.day, format_duration(slowest.total())).unwrap();
+    }
+    result
+}
+
+fn count(count: usize, noun: &str) -> String {
+    format!("{count} {noun}{}", if count == 1 { "" } else { "s" })
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_millis(1) {
+        format!("{}µs", duration.as_micros())
+    } else if duration < Duration::from_secs(1) {
+        format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+    } else {
+        format!("{:.3}s", duration.as_secs_f64())
+    }
+}
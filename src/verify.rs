@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use toml::{Table, Value};
+
+use crate::solution::Answer;
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    const ANSWERS: &str = indoc! {r#"
+        [day1]
+        part1 = 24000
+        part2 = 45000
+
+        [day5]
+        part1 = "CMZ"
+
+        [day10]
+        part2 = """
+        #.
+        .#
+        """
+    "#};
+
+    #[test]
+    fn parses_answers() {
+        let answers = ExpectedAnswers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.get(1, 1), Some(&Answer::Number(24_000)));
+        assert_eq!(answers.get(1, 2), Some(&Answer::Number(45_000)));
+        assert_eq!(answers.get(5, 1), Some(&Answer::Text("CMZ".to_string())));
+        assert_eq!(answers.get(5, 2), None);
+        assert_eq!(answers.get(10, 2), Some(&Answer::Render("#.\n.#\n".to_string())));
+    }
+
+    #[test]
+    fn rejects_malformed_answers() {
+        assert!(ExpectedAnswers::parse("[day1]\npart3 = 1").is_err());
+        assert!(ExpectedAnswers::parse("[dayX]\npart1 = 1").is_err());
+        assert!(ExpectedAnswers::parse("[day1]\npart1 = 1.5").is_err());
+        assert!(ExpectedAnswers::parse("day1 = 5").is_err());
+        assert!(ExpectedAnswers::parse("[day1").is_err());
+    }
+
+    #[test]
+    fn parses_sample_answers() {
+        let answers = parse_sample_answers("part1 = 7\npart2 = \"MCD\"").unwrap();
+        assert_eq!(answers, BTreeMap::from([(1, Answer::Number(7)), (2, Answer::Text("MCD".to_string()))]));
+        assert_eq!(parse_sample_answers("part3 = 1"), Err("sample.part3: expected part1 or part2".to_string()));
+    }
+
+    #[test]
+    fn compares_answers_by_value() {
+        assert_eq!(compare(&Answer::Number(42), &42_u32.into()), None);
+        assert_eq!(compare(&Answer::Text("CMZ".to_string()), &"CMZ".to_string().into()), None);
+        assert_eq!(compare(&Answer::Number(42), &43_u32.into()), Some("expected 42, got 43".to_string()));
+    }
+
+    #[test]
+    fn diffs_multi_line_answers() {
+        let expected = Answer::Render("##\n..\n##\n".to_string());
+        let actual = Answer::Render("##\n.#\n##\n".to_string());
+        assert_eq!(compare(&expected, &actual), Some("-..\n+.#\n".to_string()));
+    }
+}
+
+/// Locked answers, read from a TOML file with one table per day:
+///
+/// ```toml
+/// [day5]
+/// part1 = "CMZ"
+/// part2 = "MCD"
+/// ```
+///
+/// Multi-line answers like the day 10 screen are written as `"""` strings.
+#[derive(Debug, Default)]
+pub struct ExpectedAnswers {
+    answers: BTreeMap<(u32, u8), Answer>,
+}
+
+impl ExpectedAnswers {
+    pub fn parse(input: &str) -> Result<ExpectedAnswers, String> {
+        let table: Table = input.parse().map_err(|e| format!("invalid answers file: {e}"))?;
+        let mut answers = BTreeMap::new();
+        for (key, parts) in &table {
+            let day = key.strip_prefix("day").and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("expected a table named day<N>, found {key:?}"))?;
+            let parts = parts.as_table().ok_or_else(|| format!("{key} must be a table of part answers"))?;
+            for (part, answer) in parse_parts(key, parts)? {
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(ExpectedAnswers { answers })
+    }
+
+    pub fn get(&self, day: u32, part: u8) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+}
+
+/// The answers for a puzzle sample, read from a `tests/data/y<YEAR>/day<N>/sample<K>.expected` file
+/// holding the `part1` and `part2` keys of a day's table in the answers file:
+///
+/// ```toml
+/// part1 = 24000
+/// part2 = 45000
+/// ```
+pub fn parse_sample_answers(input: &str) -> Result<BTreeMap<u8, Answer>, String> {
+    let table: Table = input.parse().map_err(|e| format!("invalid sample answers: {e}"))?;
+    parse_parts("sample", &table)
+}
+
+fn parse_parts(key: &str, parts: &Table) -> Result<BTreeMap<u8, Answer>, String> {
+    let mut answers = BTreeMap::new();
+    for (part_key, value) in parts {
+        let part = match part_key.as_str() {
+            "part1" => 1,
+            "part2" => 2,
+            _ => return Err(format!("{key}.{part_key}: expected part1 or part2")),
+        };
+        let answer = match value {
+            Value::Integer(number) => Answer::Number(*number as i128),
+            Value::String(text) if text.contains('\n') => Answer::Render(text.clone()),
+            Value::String(text) => Answer::Text(text.clone()),
+            _ => return Err(format!("{key}.{part_key}: expected an integer or a string")),
+        };
+        answers.insert(part, answer);
+    }
+    Ok(answers)
+}
+
+/// `None` if `actual` matches `expected`, otherwise a description of the difference.
+///
+/// Answers are compared by how they are displayed, so a number and a string of its digits match.
+/// Multi-line answers are diffed line by line, with `-` for expected and `+` for actual lines.
+pub fn compare(expected: &Answer, actual: &Answer) -> Option<String> {
+    let (expected, actual) = (expected.to_string(), actual.to_string());
+    if expected == actual {
+        return None;
+    }
+    if !expected.contains('\n') && !actual.contains('\n') {
+        return Some(format!("expected {expected}, got {actual}"));
+    }
+    let mut diff = String::new();
+    let (mut expected_lines, mut actual_lines) = (expected.lines(), actual.lines());
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (None, None) => break,
+            (Some(e), Some(a)) if e == a => {}
+            (e, a) => {
+                if let Some(e) = e {
+                    writeln!(diff, "-{e}").unwrap();
+                }
+                if let Some(a) = a {
+                    writeln!(diff, "+{a}").unwrap();
+                }
+            }
+        }
+    }
+    if diff.is_empty() {
+        // only the trailing line break differs
+        diff = format!("expected {:?}, got {:?}\n", expected, actual);
+    }
+    Some(diff)
+}
@@ -0,0 +1,240 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+use std::{env, fs, thread};
+
+use crate::cli::Selection;
+use crate::input::{self, InputSource};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    fn output(success: bool, stdout: &str, stderr: &str) -> CommandOutput {
+        CommandOutput { success, stdout: stdout.to_string(), stderr: stderr.to_string() }
+    }
+
+    const PASSING_TESTS: &str = indoc! {"
+
+        running 12 tests
+        ............
+        test result: ok. 12 passed; 0 failed; 0 ignored; 0 measured; 123 filtered out; finished in 0.01s
+
+    "};
+
+    const FAILING_TESTS: &str = indoc! {"
+
+        running 12 tests
+        ....F.......
+        failures:
+
+        ---- y2022::day9::tests::test_part1 stdout ----
+        assertion `left == right` failed
+
+        failures:
+            y2022::day9::tests::test_part1
+
+        test result: FAILED. 11 passed; 1 failed; 0 ignored; 0 measured; 123 filtered out; finished in 0.01s
+
+    "};
+
+    const RUN: &str = indoc! {"
+        Advent, Advent, ein Lichtlein brennt:
+
+        Day9:
+          Part 1: 13
+          Part 2: 1
+    "};
+
+    #[test]
+    fn parses_test_results() {
+        assert_eq!(TestResults::parse(PASSING_TESTS), Some(TestResults { passed: 12, failed: vec![] }));
+        let failed = vec!["y2022::day9::tests::test_part1".to_string()];
+        assert_eq!(TestResults::parse(FAILING_TESTS), Some(TestResults { passed: 11, failed }));
+        assert_eq!(TestResults::parse("error: could not compile `adventofrust`"), None);
+    }
+
+    #[test]
+    fn summarizes_passing_day() {
+        let summary = summarize(9, &output(true, PASSING_TESTS, ""), &output(true, RUN, ""));
+        assert_eq!(summary, indoc! {"
+            day 9: tests ok, 12 passed
+              Part 1: 13
+              Part 2: 1
+        "});
+    }
+
+    #[test]
+    fn summarizes_failing_day() {
+        let run = output(false, "Advent, Advent, ein Lichtlein brennt:\n\nDay9:\n", "error: day 9, line 2, column 1: unsupported direction: \"X\"\n");
+        let summary = summarize(9, &output(false, FAILING_TESTS, ""), &run);
+        assert_eq!(summary, indoc! {r#"
+            day 9: tests FAILED, 11 passed, 1 failed
+              failed: y2022::day9::tests::test_part1
+              error: day 9, line 2, column 1: unsupported direction: "X"
+        "#});
+    }
+
+    #[test]
+    fn summarizes_build_failure() {
+        let build_error = "   Compiling adventofrust v0.1.0\nerror[E0308]: mismatched types\n --> src/day9.rs:3:5\nerror: could not compile `adventofrust`\n";
+        let summary = summarize(9, &output(false, "", build_error), &output(false, "", build_error));
+        assert_eq!(summary, indoc! {"
+            day 9: does not build
+              error[E0308]: mismatched types
+               --> src/day9.rs:3:5
+        "});
+    }
+
+    #[test]
+    fn notices_changed_files() {
+        let dir = env::temp_dir().join(format!("adventofrust-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("day1.rs");
+        let paths = [file.clone()];
+        let missing = modification_times(&paths);
+        fs::write(&file, "// day 1").unwrap();
+        let written = modification_times(&paths);
+        assert_ne!(missing, written);
+        assert_eq!(written, modification_times(&paths));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+/// How often the watched files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Re-runs the tests and the solution of the selected day whenever its source file or its input
+/// changes, until interrupted.
+///
+/// Both run through `cargo`, so that changes to the source get compiled first.
+pub fn watch(selection: Selection) -> ! {
+    let day = selection.days[0];
+    let paths = watched_paths(&selection);
+    let names: Vec<_> = paths.iter().map(|path| path.display().to_string()).collect();
+    println!("Watching {} for changes, press Ctrl-C to stop", names.join(" and "));
+    let mut last_seen = None;
+    loop {
+        let seen = modification_times(&paths);
+        if last_seen.as_ref() != Some(&seen) {
+            last_seen = Some(seen);
+            let filter = format!("y{}::day{day}::", selection.year);
+            let tests = cargo(&["test".to_string(), "--quiet".to_string(), "--lib".to_string(), "--".to_string(), filter]);
+            let run = cargo(&run_args(&selection));
+            print!("\n{}", summarize(day, &tests, &run));
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// The day's source file and its input.
+fn watched_paths(selection: &Selection) -> Vec<PathBuf> {
+    let (year, day) = (selection.year, selection.days[0]);
+    let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join(format!("y{year}")).join(format!("day{day}.rs"));
+    let input = match &selection.input {
+        Some(InputSource::File(path)) => path.clone(),
+        Some(InputSource::Directory(dir) | InputSource::Download(dir)) => input::day_input_path(dir, day),
+        Some(InputSource::Stdin) | None => input::day_input_path(&input::default_inputs_dir(year), day),
+    };
+    vec![source, input]
+}
+
+/// When each of `paths` was last modified, `None` for files that do not exist (yet).
+fn modification_times(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths.iter().map(|path| fs::metadata(path).and_then(|m| m.modified()).ok()).collect()
+}
+
+/// Arguments for `cargo` to solve the watched day with the options it is watched with.
+fn run_args(selection: &Selection) -> Vec<String> {
+    let mut args: Vec<String> = ["run", "--quiet", "--", "run"].map(String::from).to_vec();
+    args.push(selection.days[0].to_string());
+    args.push(format!("--year={}", selection.year));
+    if let Some(part) = selection.part {
+        args.push(format!("--part={part}"));
+    }
+    match &selection.input {
+        Some(InputSource::File(path)) => args.push(format!("--input={}", path.display())),
+        Some(InputSource::Directory(dir) | InputSource::Download(dir)) => args.push(format!("--inputs-dir={}", dir.display())),
+        Some(InputSource::Stdin) | None => {}
+    }
+    if selection.fetch {
+        args.push("--fetch".to_string());
+    }
+    args
+}
+
+struct CommandOutput {
+    success: bool,
+    stdout: String,
+    stderr: String,
+}
+
+fn cargo(args: &[String]) -> CommandOutput {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    match Command::new(cargo).arg(&args[0]).arg("--manifest-path").arg(manifest).args(&args[1..]).output() {
+        Ok(output) => CommandOutput {
+            success: output.status.success(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        },
+        Err(error) => CommandOutput { success: false, stdout: String::new(), stderr: format!("error: could not run cargo: {error}\n") },
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct TestResults {
+    passed: usize,
+    /// Names of the failed tests.
+    failed: Vec<String>,
+}
+
+impl TestResults {
+    /// Reads the results from the output of `cargo test`, `None` if the tests did not run.
+    fn parse(output: &str) -> Option<TestResults> {
+        let result = output.lines().find_map(|line| line.strip_prefix("test result: "))?;
+        let passed = result.split("; ")
+            .find_map(|count| count.split_once(". ").map_or(count, |(_, count)| count).strip_suffix(" passed"))?
+            .parse().ok()?;
+        let failed = output.lines()
+            .filter_map(|line| line.strip_prefix("---- ")?.strip_suffix(" stdout ----"))
+            .map(String::from)
+            .collect();
+        Some(TestResults { passed, failed })
+    }
+}
+
+/// A few lines about how the day's tests went, followed by its answers or errors.
+fn summarize(day: u32, tests: &CommandOutput, run: &CommandOutput) -> String {
+    let mut result = String::new();
+    let Some(results) = TestResults::parse(&tests.stdout) else {
+        writeln!(result, "day {day}: does not build").unwrap();
+        // the first compiler error, up to the next blank line or message
+        let error = tests.stderr.lines()
+            .skip_while(|line| !line.starts_with("error"))
+            .enumerate()
+            .take_while(|(idx, line)| *idx == 0 || !(line.is_empty() || line.starts_with("error") || line.starts_with("warning")))
+            .map(|(_, line)| line);
+        for line in error {
+            writeln!(result, "  {line}").unwrap();
+        }
+        return result;
+    };
+    if tests.success {
+        writeln!(result, "day {day}: tests ok, {} passed", results.passed).unwrap();
+    } else {
+        writeln!(result, "day {day}: tests FAILED, {} passed, {} failed", results.passed, results.failed.len()).unwrap();
+        for name in &results.failed {
+            writeln!(result, "  failed: {name}").unwrap();
+        }
+    }
+    let answers = run.stdout.lines().skip_while(|line| *line != format!("Day{day}:")).skip(1);
+    let errors = run.stderr.lines().filter(|line| line.starts_with("error"));
+    for line in answers.chain(errors) {
+        writeln!(result, "{}{line}", if line.starts_with("  ") { "" } else { "  " }).unwrap();
+    }
+    result
+}
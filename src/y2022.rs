@@ -0,0 +1,37 @@
+//! The [Advent of Code 2022](https://adventofcode.com/2022) puzzles, with each day's input in
+//! `src/y2022/day<N>/input.txt`.
+
+use crate::Days;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day11;
+pub mod day12;
+pub mod day10;
+pub mod day13;
+pub mod day14;
+
+/// Every solved day, in order. Adding a day means adding its entry here.
+pub const DAYS: Days = &[
+    (1, &day1::Day1),
+    (2, &day2::Day2),
+    (3, &day3::Day3),
+    (4, &day4::Day4),
+    (5, &day5::Day5),
+    (6, &day6::Day6),
+    (7, &day7::Day7),
+    (8, &day8::Day8),
+    (9, &day9::Day9),
+    (10, &day10::Day10),
+    (11, &day11::Day11),
+    (12, &day12::Day12),
+    (13, &day13::Day13),
+    (14, &day14::Day14),
+];
@@ -0,0 +1,232 @@
+use std::io::BufRead;
+
+use crate::error::{Error, Result};
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    const SAMPLE_INPUT: &str = indoc! {"1000
+        2000
+        3000
+
+        4000
+
+        5000
+        6000
+
+        7000
+        8000
+        9000
+
+        10000
+    "};
+
+    #[test]
+    fn it_finds_most_calories() {
+        assert_eq!(super::most_calories(SAMPLE_INPUT), Ok(24_000))
+    }
+    #[test]
+    fn it_finds_top_three_calories() {
+        assert_eq!(super::top3_calories(SAMPLE_INPUT), Ok(45_000))
+    }
+    #[test]
+    fn it_reports_invalid_calories() {
+        let error = super::most_calories("1000\n\n20x0\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (3, 1, "20x0"));
+        let error = super::most_calories("1\n\n18446744073709551615\n1\n").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (4, "too many calories for one elf"));
+        assert!(super::top3_calories("18446744073709551615\n\n1\n").is_err());
+        // the first line that fails is reported, even if a later one is not a number at all
+        let error = super::most_calories("18446744073709551615\n1\nx\n").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (2, "too many calories for one elf"));
+    }
+
+    #[test]
+    fn adds_up_more_than_u32_calories() {
+        assert_eq!(super::most_calories("4000000000\n1000000000\n"), Ok(5_000_000_000));
+    }
+
+    #[test]
+    fn streams_the_same_answers() {
+        for input in [SAMPLE_INPUT, "", "\n\n7\n\n\n3\n5", "1\n2\n\n4\n\n1\n\n9"] {
+            assert_eq!(most_calories_from(input.as_bytes()), most_calories(input), "{input:?}");
+            assert_eq!(top3_calories_from(input.as_bytes()), top3_calories(input), "{input:?}");
+        }
+        for input in ["1000\n\n20x0\n", "1\n\n18446744073709551615\n1\n", "18446744073709551615\n\n1\n", "18446744073709551615\n1\nx\n"] {
+            assert_eq!(top3_calories_from(input.as_bytes()), top3_calories(input), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn reports_unreadable_lines() {
+        let error = most_calories_from(&b"1000\n\xff\n"[..]).unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (2, "could not read the line: stream did not contain valid UTF-8"));
+    }
+
+    #[test]
+    fn ranks_elves() {
+        let elves = Elves::parse(SAMPLE_INPUT).unwrap();
+        assert_eq!(elves.calories(), [6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(elves.ranking(), vec![(3, 24000), (2, 11000), (4, 10000), (0, 6000), (1, 4000)]);
+        assert_eq!(elves.top_n(2), Some(35_000));
+        assert_eq!(elves.top_n(9), Some(55_000));
+        assert_eq!(Elves::parse("1\n\n2\n\n1\n").unwrap().ranking(), vec![(1, 2), (0, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn sums_up_the_elves() {
+        let elves = Elves::parse(SAMPLE_INPUT).unwrap();
+        assert_eq!((elves.total(), elves.mean(), elves.median()), (Some(55_000), Some(11_000.0), Some(10_000.0)));
+        let elves = Elves::parse("1\n\n2\n\n4\n\n8\n").unwrap();
+        assert_eq!((elves.mean(), elves.median()), (Some(3.75), Some(3.0)));
+        let elves = Elves::parse("").unwrap();
+        assert_eq!((elves.total(), elves.top_n(3), elves.mean(), elves.median()), (Some(0), Some(0), None, None));
+    }
+}
+
+pub fn most_calories(input: &str) -> Result<u64> {
+    Ok(Elves::parse(input)?.calories().iter().copied().max().unwrap_or(0))
+}
+
+pub fn top3_calories(input: &str) -> Result<u64> {
+    Elves::parse(input)?.top_n(3).ok_or_else(|| Error::new("", "the top three elves carry too many calories to add up"))
+}
+
+/// Like [`most_calories`], but reading `input` line by line.
+pub fn most_calories_from(input: impl BufRead) -> Result<u64> {
+    Ok(top_calories_from(input, 1)?.first().copied().unwrap_or(0))
+}
+
+/// Like [`top3_calories`], but reading `input` line by line.
+pub fn top3_calories_from(input: impl BufRead) -> Result<u64> {
+    top_calories_from(input, 3)?.iter().try_fold(0_u64, |total, &calories| total.checked_add(calories))
+        .ok_or_else(|| Error::new("", "the top three elves carry too many calories to add up"))
+}
+
+/// The calories of the `n` elves carrying the most, most first, keeping only those in memory.
+fn top_calories_from(mut input: impl BufRead, n: usize) -> Result<Vec<u64>> {
+    let mut top = Vec::with_capacity(n + 1);
+    let mut consider = |calories: u64| {
+        top.insert(top.partition_point(|&other| other >= calories), calories);
+        top.truncate(n);
+    };
+    // the calories of the current elf, `None` between elves
+    let mut elf: Option<u64> = None;
+    let mut line = String::new();
+    for line_idx in 0.. {
+        line.clear();
+        let error = |e: Error| e.on_line(line_idx + 1);
+        if input.read_line(&mut line).map_err(|e| error(Error::new("", format!("could not read the line: {e}"))))? == 0 {
+            break;
+        }
+        let line = line.strip_suffix('\n').map(|line| line.strip_suffix('\r').unwrap_or(line)).unwrap_or(&line);
+        if line.is_empty() {
+            elf.take().into_iter().for_each(&mut consider);
+            continue;
+        }
+        elf = Some(add_item(elf.unwrap_or(0), line).map_err(error)?);
+    }
+    elf.into_iter().for_each(consider);
+    Ok(top)
+}
+
+/// Adds the calories of the item on `line` to the `sum` of an elf's earlier items.
+fn add_item(sum: u64, line: &str) -> Result<u64> {
+    let calories = parse::line(line, |s| s.unsigned::<u64>("a number of calories"))?;
+    sum.checked_add(calories).ok_or_else(|| Error::in_line(line, line, "too many calories for one elf"))
+}
+
+/// The calories every elf carries, in the order of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elves {
+    calories: Vec<u64>,
+}
+
+impl Elves {
+    /// Parses the items of every elf, one number of calories per line and a blank line between elves.
+    pub fn parse(input: &str) -> Result<Elves> {
+        let calories = parse::blocks(input)
+            .map(|(first_line, block)| Elves::parse_elf(block).map_err(|e| e.in_block(first_line)))
+            .collect::<Result<_>>()?;
+        Ok(Elves { calories })
+    }
+
+    /// The calories of an elf, reporting the first line that is not a number or does not fit.
+    fn parse_elf(block: &str) -> Result<u64> {
+        block.lines().enumerate().try_fold(0_u64, |sum, (line_idx, line)| add_item(sum, line).map_err(|e| e.on_line(line_idx + 1)))
+    }
+
+    /// The calories of every elf, by its index in the input.
+    pub fn calories(&self) -> &[u64] {
+        &self.calories
+    }
+
+    /// The index and the calories of every elf, most calories first and by index among equals.
+    pub fn ranking(&self) -> Vec<(usize, u64)> {
+        let mut ranking: Vec<_> = self.calories.iter().copied().enumerate().collect();
+        ranking.sort_by(|(_, left), (_, right)| right.cmp(left));
+        ranking
+    }
+
+    /// The calories of the `n` elves carrying the most, `None` if they do not fit into a `u64`.
+    pub fn top_n(&self, n: usize) -> Option<u64> {
+        self.ranking().iter().take(n).try_fold(0_u64, |total, &(_, calories)| total.checked_add(calories))
+    }
+
+    /// The calories of all elves, `None` if they do not fit into a `u64`.
+    pub fn total(&self) -> Option<u64> {
+        self.calories.iter().try_fold(0_u64, |total, &calories| total.checked_add(calories))
+    }
+
+    /// The average calories per elf, `None` without elves.
+    pub fn mean(&self) -> Option<f64> {
+        if self.calories.is_empty() {
+            return None;
+        }
+        Some(self.calories.iter().map(|&calories| calories as f64).sum::<f64>() / self.calories.len() as f64)
+    }
+
+    /// The calories of the middle elf of the ranking, or the mean of the two middle ones.
+    pub fn median(&self) -> Option<f64> {
+        let ranking = self.ranking();
+        let middle = ranking.len() / 2;
+        match ranking.len() {
+            0 => None,
+            len if len % 2 == 1 => Some(ranking[middle].1 as f64),
+            _ => Some((ranking[middle - 1].1 as f64 + ranking[middle].1 as f64) / 2.0),
+        }
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(most_calories(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        Some(top3_calories(input).map(Answer::from))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(Elves::parse(input).map(drop))
+    }
+
+    fn streams(&self) -> bool {
+        true
+    }
+
+    fn solve_lines(&self, part: u8, input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        match part {
+            1 => Some(most_calories_from(input).map(Answer::from)),
+            2 => Some(top3_calories_from(input).map(Answer::from)),
+            _ => None,
+        }
+    }
+}
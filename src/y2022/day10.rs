@@ -1,13 +1,13 @@
 use itertools::FoldWhile::{Continue, Done};
 use itertools::Itertools;
-use crate::day10::Instruction::*;
+use crate::y2022::day10::Instruction::*;
+use crate::error::{Error, Result};
+use crate::parse::{self, Scanner};
+use crate::repl::{argument, Session};
+use crate::solution::{Answer, Solution};
 
 #[cfg(test)]
 mod tests {
-    extern crate test;
-
-    // use test::Bencher;
-
     use indoc::indoc;
     use itertools::Itertools;
     use super::*;
@@ -169,85 +169,85 @@ mod tests {
     // }
     #[test]
     fn parses_first_line() {
-        let cathode_ray = CathodeRay::new(&SAMPLE_INPUT.lines().take(1).join("\n"));
+        let cathode_ray = CathodeRay::new(&SAMPLE_INPUT.lines().take(1).join("\n")).unwrap();
         assert_eq!(cathode_ray.instructions, vec![Addx(15)]);
     }
 
     #[test]
     fn parses_noop() {
-        let cathode_ray = CathodeRay::new("noop");
+        let cathode_ray = CathodeRay::new("noop").unwrap();
         assert_eq!(cathode_ray.instructions, vec![Noop]);
     }
 
     #[test]
     fn parses_instructions() {
-        let cathode_ray = CathodeRay::new(SAMPLE_INPUT);
+        let cathode_ray = CathodeRay::new(SAMPLE_INPUT).unwrap();
         assert_eq!(cathode_ray.instructions.len(), 146);
     }
 
     #[test]
     fn computes_x_during_cycle_1() {
-        let cathode_ray = CathodeRay::new(SAMPLE_INPUT);
+        let cathode_ray = CathodeRay::new(SAMPLE_INPUT).unwrap();
         assert_eq!(cathode_ray.register_x_during_cycle(1), 1)
     }
 
     #[test]
     fn computes_x_during_cycle_2() {
-        let cathode_ray = CathodeRay::new(SAMPLE_INPUT);
+        let cathode_ray = CathodeRay::new(SAMPLE_INPUT).unwrap();
         assert_eq!(cathode_ray.register_x_during_cycle(2), 1)
     }
 
     #[test]
     fn computes_x_during_cycle_3() {
-        let cathode_ray = CathodeRay::new(SAMPLE_INPUT);
+        let cathode_ray = CathodeRay::new(SAMPLE_INPUT).unwrap();
         assert_eq!(cathode_ray.register_x_during_cycle(3), 16)
     }
 
     #[test]
     fn computes_x_during_cycle_4() {
-        let cathode_ray = CathodeRay::new(SAMPLE_INPUT);
+        let cathode_ray = CathodeRay::new(SAMPLE_INPUT).unwrap();
         assert_eq!(cathode_ray.register_x_during_cycle(4), 16)
     }
 
     #[test]
     fn computes_signal_strength() {
-        let cathode_ray = CathodeRay::new(SAMPLE_INPUT);
+        let cathode_ray = CathodeRay::new(SAMPLE_INPUT).unwrap();
         assert_eq!(cathode_ray.register_x_during_cycle(20) * 20, 420);
     }
 
     #[test]
     fn computes_signal_strength_60() {
-        let cathode_ray = CathodeRay::new(SAMPLE_INPUT);
+        let cathode_ray = CathodeRay::new(SAMPLE_INPUT).unwrap();
         assert_eq!(cathode_ray.register_x_during_cycle(60) * 60, 1140);
     }
 
     #[test]
     fn computes_signal_strength_100() {
-        let cathode_ray = CathodeRay::new(SAMPLE_INPUT);
+        let cathode_ray = CathodeRay::new(SAMPLE_INPUT).unwrap();
         assert_eq!(cathode_ray.signal_strength_at_cycle(100), 1800);
     }
 
     #[test]
     fn computes_signal_strength_140() {
-        let cathode_ray = CathodeRay::new(SAMPLE_INPUT);
+        let cathode_ray = CathodeRay::new(SAMPLE_INPUT).unwrap();
         assert_eq!(cathode_ray.signal_strength_at_cycle(140), 2940);
     }
 
     #[test]
     fn computes_signal_strength_180() {
-        let cathode_ray = CathodeRay::new(SAMPLE_INPUT);
+        let cathode_ray = CathodeRay::new(SAMPLE_INPUT).unwrap();
         assert_eq!(cathode_ray.signal_strength_at_cycle(180), 2880);
     }
 
     #[test]
     fn computes_signal_strength_220() {
-        let cathode_ray = CathodeRay::new(SAMPLE_INPUT);
+        let cathode_ray = CathodeRay::new(SAMPLE_INPUT).unwrap();
         assert_eq!(cathode_ray.signal_strength_at_cycle(220), 3960);
     }
 
     #[test]
     fn computes_sum_of_signla_strengths() {
-        let cathode_ray = CathodeRay::new(SAMPLE_INPUT);
+        let cathode_ray = CathodeRay::new(SAMPLE_INPUT).unwrap();
         assert_eq!(cathode_ray.sum_interesting_signal_strengths(), 13140);
     }
 
@@ -262,39 +262,75 @@ mod tests {
 
     #[test]
     fn renders_screen() {
-        let cathode_ray = CathodeRay::new(SAMPLE_INPUT);
+        let cathode_ray = CathodeRay::new(SAMPLE_INPUT).unwrap();
         assert_eq!(cathode_ray.render_screen(), SAMPLE_SCREEN);
     }
+
+    #[test]
+    fn explores_register() {
+        let mut session = Day10.explore(SAMPLE_INPUT).unwrap().unwrap();
+        assert_eq!(session.execute("cycle", &["220"]), Ok("X = 18, signal strength 3960".to_string()));
+        assert_eq!(session.execute("screen", &[]), Ok(SAMPLE_SCREEN.to_string()));
+        assert!(session.execute("cycle", &[]).is_err());
+    }
+
+    #[test]
+    fn reports_invalid_instructions() {
+        let error = CathodeRay::new("noop\nmulx 3\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 1, "mulx 3"));
+        let error = CathodeRay::new("addx 1\naddx x\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 6, "x"));
+    }
+
+    #[test]
+    fn does_not_overflow_the_register() {
+        let cathode_ray = CathodeRay::new(&"addx 2000000000\n".repeat(10)).unwrap();
+        assert_eq!(cathode_ray.register_x_during_cycle(21), 20_000_000_001);
+        assert_eq!(cathode_ray.signal_strength_at_cycle(21), 420_000_000_021);
+    }
 }
 
-struct CathodeRay {
-    instructions: Vec<Instruction>,
+pub struct CathodeRay {
+    pub instructions: Vec<Instruction>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
-enum Instruction {
+pub enum Instruction {
     Noop,
     Addx(i32),
 }
 
-impl From<&str> for Instruction {
-    fn from(value: &str) -> Self {
-        if value.starts_with("noop") {
-            Noop
+impl TryFrom<&str> for Instruction {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        parse::line(value, Instruction::parse)
+    }
+}
+
+impl Instruction {
+    fn parse(s: &mut Scanner) -> Result<Instruction> {
+        if s.accept("noop") {
+            Ok(Noop)
+        } else if s.accept("addx ") {
+            Ok(Addx(s.signed("an addx value")?))
         } else {
-            Addx(value[5..].parse::<i32>().expect("could not parse addx value"))
+            Err(s.error(s.line(), "unknown instruction"))
         }
     }
 }
 
 impl CathodeRay {
-    pub(crate) fn new(input: &str) -> CathodeRay
+    pub fn new(input: &str) -> Result<CathodeRay>
     {
-        CathodeRay {
-            instructions: input.lines().map(Instruction::from).collect(),
-        }
+        let instructions = input.lines()
+            .enumerate()
+            .map(|(line_idx, line)| Instruction::try_from(line).map_err(|e| e.on_line(line_idx + 1)))
+            .collect::<Result<_>>()?;
+        Ok(CathodeRay { instructions })
     }
-    pub(crate) fn register_x_during_cycle(&self, cycle: u32) -> i32 {
+    /// The X register during `cycle`, wide enough not to overflow however large the `addx` values.
+    pub fn register_x_during_cycle(&self, cycle: u32) -> i64 {
         if cycle <= 1 {
             return 1;
         }
@@ -304,24 +340,24 @@ impl CathodeRay {
                 Done((state.0 + 1, state.1))
             } else {
                 match instr {
-                    Addx(val) => Continue((state.0 + 2, state.1 + val)),
+                    Addx(val) => Continue((state.0 + 2, state.1 + i64::from(*val))),
                     Noop => Continue((state.0 + 1, state.1)),
                 }
             }
         }).into_inner().1
     }
-    pub fn signal_strength_at_cycle(&self, cycle: u32) -> i32 {
-        cycle as i32 * self.register_x_during_cycle(cycle)
+    pub fn signal_strength_at_cycle(&self, cycle: u32) -> i64 {
+        i64::from(cycle) * self.register_x_during_cycle(cycle)
     }
-    pub(crate) fn sum_interesting_signal_strengths(&self) -> i32 {
+    pub fn sum_interesting_signal_strengths(&self) -> i64 {
         [20, 60, 100, 140, 180, 220].map(|cycle| { self.signal_strength_at_cycle(cycle) }).iter().sum()
     }
-    pub(crate) fn render_screen(&self) -> String {
+    pub fn render_screen(&self) -> String {
         let mut result = String::with_capacity(6 * 40);
         for n in 1..=6 * 40 {
             let x = self.register_x_during_cycle(n);
             // println!("Sprite position : {}", sprite_position(&x));
-            let pos = ((n - 1) % 40) as i32;
+            let pos = ((n - 1) % 40) as i64;
             // println!("During cycle {n:3}: CRT draws pixel in position {pos}");
             if (pos - 1..=pos + 1).contains(&x) {
                 result.push('#');
@@ -346,10 +382,51 @@ impl CathodeRay {
 //     }).collect()
 // }
 
-pub(crate) fn signal_strength(input: &str) -> i32 {
-    CathodeRay::new(input).sum_interesting_signal_strengths()
+pub fn signal_strength(input: &str) -> Result<i64> {
+    Ok(CathodeRay::new(input)?.sum_interesting_signal_strengths())
+}
+
+pub fn print(input: &str) -> Result<String> {
+    Ok(CathodeRay::new(input)?.render_screen())
 }
 
-pub fn print(input: &str) -> String {
-    CathodeRay::new(input).render_screen()
-}
\ No newline at end of file
+/// Looks at the X register during any cycle of a program.
+struct Explorer {
+    ray: CathodeRay,
+}
+
+impl Session for Explorer {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[("cycle <N>", "the X register and the signal strength during cycle N"), ("screen", "what the CRT draws")]
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> std::result::Result<String, String> {
+        match command {
+            "cycle" => {
+                let cycle = argument(args, "N", None)?;
+                Ok(format!("X = {}, signal strength {}", self.ray.register_x_during_cycle(cycle), self.ray.signal_strength_at_cycle(cycle)))
+            }
+            _ => Ok(self.ray.render_screen()),
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(signal_strength(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        Some(print(input).map(Answer::Render))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(CathodeRay::new(input).map(drop))
+    }
+
+    fn explore(&self, input: &str) -> Option<Result<Box<dyn Session>>> {
+        Some(CathodeRay::new(input).map(|ray| Box::new(Explorer { ray }) as Box<dyn Session>))
+    }
+}
@@ -0,0 +1,443 @@
+use std::collections::VecDeque;
+
+use itertools::Itertools;
+use num_integer::Integer;
+
+use crate::error::{Error, Result};
+use crate::parse::{self, Lines, Scanner};
+use crate::repl::{argument, Session};
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use itertools::Itertools;
+
+    use crate::solution::Solution;
+    use crate::y2022::day11::{Day11, Monkey, monkey_business, parse_monkeys, validate};
+
+    const SAMPLE_INPUT: &str = indoc! {"
+        Monkey 0:
+          Starting items: 79, 98
+          Operation: new = old * 19
+          Test: divisible by 23
+            If true: throw to monkey 2
+            If false: throw to monkey 3
+
+        Monkey 1:
+          Starting items: 54, 65, 75, 74
+          Operation: new = old + 6
+          Test: divisible by 19
+            If true: throw to monkey 2
+            If false: throw to monkey 0
+
+        Monkey 2:
+          Starting items: 79, 60, 97
+          Operation: new = old * old
+          Test: divisible by 13
+            If true: throw to monkey 1
+            If false: throw to monkey 3
+
+        Monkey 3:
+          Starting items: 74
+          Operation: new = old + 3
+          Test: divisible by 17
+            If true: throw to monkey 0
+            If false: throw to monkey 1
+        "};
+
+    #[test]
+    fn test_parses_monkey() {
+        let monkey = Monkey::new(&SAMPLE_INPUT.lines().take(6).join("\n")).unwrap();
+        assert_eq!(monkey.items, vec![79_u32.into(), 98_u32.into()]);
+        assert_eq!((monkey.operation)(1_u32.into()), Some(19_u32.into()));
+        assert_eq!((monkey.operation)(2_u32.into()), Some((2_u32 * 19_u32).into()));
+        assert_eq!((monkey.operation)(u64::MAX), None);
+        assert_eq!(monkey.test_divisor, 23_u32.into());
+        assert_eq!(monkey.true_target, 2);
+        assert_eq!(monkey.false_target, 3);
+    }
+
+    #[test]
+    fn validates_monkey_lines() {
+        assert_eq!(validate(SAMPLE_INPUT), vec![]);
+        let input = SAMPLE_INPUT.replace("  Operation: new = old + 6\n", "").replace("\n\nMonkey 3", "\nMonkey 3");
+        let violations = validate(&input);
+        let found: Vec<_> = violations.iter().map(|e| (e.line, e.text.as_str(), e.message.as_str())).collect();
+        assert_eq!(found, vec![
+            (8, "Monkey 1:", "expected 6 lines describing the monkey, found 5"),
+            (14, "Monkey 2:", "expected 6 lines describing the monkey, found 12"),
+        ]);
+    }
+
+    #[test]
+    fn test_parses_multiple_monkeys() {
+        let mut monkeys = parse_monkeys(SAMPLE_INPUT).unwrap();
+        let last = monkeys.pop().unwrap();
+        assert_eq!(last.items, vec![74_u32.into()]);
+        assert_eq!((last.operation)(1_u32.into()), Some(4_u32.into()));
+        assert_eq!((last.operation)(2_u32.into()), Some(5_u32.into()));
+        assert_eq!(last.test_divisor, 17_u32.into());
+        assert_eq!(last.true_target, 0);
+        assert_eq!(last.false_target, 1);
+    }
+
+    #[test]
+    fn test_throw() {
+        let mut monkeys = parse_monkeys(SAMPLE_INPUT).unwrap();
+        let modulus = super::modulus(&monkeys).unwrap();
+        let (item, target) = monkeys[2].throw(&modulus, true).unwrap().unwrap();
+        assert_eq!(target, 1);
+        assert_eq!(item, 2080_u32.into());
+        let (item, target) = monkeys[2].throw(&modulus, true).unwrap().unwrap();
+        assert_eq!(target, 3);
+        assert_eq!(item, 1200_u32.into());
+        let (item, target) = monkeys[2].throw(&modulus, true).unwrap().unwrap();
+        assert_eq!(target, 3);
+        assert_eq!(item, 3136_u32.into());
+        assert_eq!(monkeys[2].throw(&modulus, true), Ok(None));
+    }
+
+    #[test]
+    fn test_round() {
+        let mut monkeys = parse_monkeys(SAMPLE_INPUT).unwrap();
+        let modulus = super::modulus(&monkeys).unwrap();
+        super::round(&mut monkeys, true, &modulus).unwrap();
+        assert_eq!(monkeys[0].items, vec![20_u32.into(), 23_u32.into(), 27_u32.into(), 26_u32.into()]);
+        assert_eq!(monkeys[1].items, vec![2080_u32.into(), 25_u32.into(), 167_u32.into(), 207_u32.into(), 401_u32.into(), 1046_u32.into()]);
+        assert!(monkeys[2].items.is_empty());
+        assert!(monkeys[3].items.is_empty());
+    }
+
+    #[test]
+    fn test_round_2() {
+        let mut monkeys = parse_monkeys(SAMPLE_INPUT).unwrap();
+        let modulus = super::modulus(&monkeys).unwrap();
+        super::round(&mut monkeys, true, &modulus).unwrap();
+        super::round(&mut monkeys, true, &modulus).unwrap();
+        assert_eq!(monkeys[0].items, vec![695_u32.into(), 10_u32.into(), 71_u32.into(), 135_u32.into(), 350_u32.into()]);
+        assert_eq!(monkeys[1].items, vec![43_u32.into(), 49_u32.into(), 58_u32.into(), 55_u32.into(), 362_u32.into()]);
+        assert!(monkeys[2].items.is_empty());
+        assert!(monkeys[3].items.is_empty());
+    }
+
+    #[test]
+    fn test_round_20() {
+        let mut monkeys = parse_monkeys(SAMPLE_INPUT).unwrap();
+        let modulus = super::modulus(&monkeys).unwrap();
+        for _ in 0..20 {
+            super::round(&mut monkeys, true, &modulus).unwrap();
+        }
+        assert_eq!(monkeys[0].items, vec![10_u32.into(), 12_u32.into(), 14_u32.into(), 26_u32.into(), 34_u32.into()]);
+        assert_eq!(monkeys[1].items, vec![245_u32.into(), 93_u32.into(), 53_u32.into(), 199_u32.into(), 115_u32.into()]);
+        assert!(monkeys[2].items.is_empty());
+        assert!(monkeys[3].items.is_empty());
+    }
+
+    #[test]
+    fn test_item_counts() {
+        let mut monkeys = parse_monkeys(SAMPLE_INPUT).unwrap();
+        let modulus = super::modulus(&monkeys).unwrap();
+        for _ in 0..20 {
+            super::round(&mut monkeys, true, &modulus).unwrap();
+        }
+        assert_eq!(monkeys[0].item_count, 101);
+        assert_eq!(monkeys[1].item_count, 95);
+        assert_eq!(monkeys[2].item_count, 7);
+        assert_eq!(monkeys[3].item_count, 105);
+    }
+
+    #[test]
+    fn test_monkey_business() {
+        assert_eq!(monkey_business(SAMPLE_INPUT, 20, true), Ok(10605));
+    }
+
+    #[test]
+    fn test_monkey_business2() {
+        assert_eq!(monkey_business(SAMPLE_INPUT, 10_000, false), Ok(52166 * 52013));
+    }
+
+    #[test]
+    fn explores_rounds() {
+        let mut session = Day11.explore(SAMPLE_INPUT).unwrap().unwrap();
+        assert_eq!(session.execute("round", &[]), Ok("after round 1, monkey business is 20".to_string()));
+        assert_eq!(session.execute("inventory", &[]), Ok(indoc! {"
+            Monkey 0: 20, 23, 27, 26 (inspected 2 items)
+            Monkey 1: 2080, 25, 167, 207, 401, 1046 (inspected 4 items)
+            Monkey 2: nothing (inspected 3 items)
+            Monkey 3: nothing (inspected 5 items)
+        "}.to_string()));
+        assert_eq!(session.execute("round", &["19"]), Ok("after round 20, monkey business is 10605".to_string()));
+        assert_eq!(session.execute("relief", &["off"]), Ok("worry levels are no longer divided by 3".to_string()));
+        assert!(session.execute("relief", &["maybe"]).is_err());
+    }
+
+    #[test]
+    fn reports_invalid_monkeys() {
+        let error = parse_monkeys(&SAMPLE_INPUT.replace("old + 6", "old - 6")).err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (10, 24, "-"));
+        let error = parse_monkeys(&SAMPLE_INPUT.replace("79, 60, 97", "79, x, 97")).err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (16, 23, "x"));
+        let error = parse_monkeys(&SAMPLE_INPUT.replace("throw to monkey 3", "throw to monkey 4")).err().unwrap();
+        assert_eq!((error.line, error.message.as_str()), (6, "there is no monkey 4"));
+        let error = parse_monkeys(&SAMPLE_INPUT.replace("If true: throw to monkey 1", "If true: throw to monkey 2")).err().unwrap();
+        assert_eq!((error.line, error.message.as_str()), (19, "monkey 2 throws to itself"));
+        let error = parse_monkeys(&SAMPLE_INPUT.replace("divisible by 13", "divisible by 0")).err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (18, 22, "0"));
+        let error = parse_monkeys(SAMPLE_INPUT.lines().take(4).join("\n").as_str()).err().unwrap();
+        assert_eq!(error.message, "expected 6 lines per monkey, found 4");
+    }
+
+    #[test]
+    fn reports_divisors_too_large_to_multiply() {
+        let input = SAMPLE_INPUT.replace("divisible by 23", "divisible by 18446744073709551615")
+            .replace("divisible by 19", "divisible by 18446744073709551615");
+        let error = monkey_business(&input, 20, true).unwrap_err();
+        assert_eq!(error.message, "the product of the test divisors does not fit into 64 bits");
+        assert_eq!(Day11.explore(&input).unwrap().err(), Some(error));
+    }
+
+    #[test]
+    fn reports_worry_levels_overflowing_in_an_operation() {
+        let input = SAMPLE_INPUT.replace("79, 60, 97", "5000000000");
+        for (rounds, decrease_worry_level) in [(20, true), (10_000, false)] {
+            let error = monkey_business(&input, rounds, decrease_worry_level).unwrap_err();
+            assert_eq!((error.line, error.message.as_str()), (17, "worry level 5000000000 does not fit into 64 bits after the operation"));
+        }
+        let mut session = Day11.explore(&input).unwrap().unwrap();
+        assert!(session.execute("round", &[]).is_err());
+    }
+}
+
+pub struct Monkey {
+    pub items: VecDeque<u64>,
+    /// `None` if the new worry level does not fit into a `u64`.
+    operation: Box<dyn Fn(u64) -> Option<u64>>,
+    /// The line of the operation, to report overflowing worry levels on.
+    operation_line: usize,
+    pub test_divisor: u64,
+    pub true_target: u32,
+    pub false_target: u32,
+    pub item_count: u64,
+}
+
+impl Monkey {
+    /// Parses the six lines describing a single monkey. Line numbers in errors count from its first line.
+    pub fn new(input: &str) -> Result<Monkey> {
+        let line_count = input.lines().count();
+        if line_count != 6 {
+            return Err(Error::new("", format!("expected 6 lines per monkey, found {line_count}")));
+        }
+        let mut lines = Lines::new(input);
+        lines.next_line(|s| {
+            s.expect("Monkey ")?;
+            s.unsigned::<u32>("a monkey number")?;
+            s.expect(":")
+        })?;
+        let items = lines.next_line(|s| s.field("Starting items", |s| s.list(", ", |s| s.unsigned("a worry level"))))?;
+        let operation = lines.next_line(|s| s.field("Operation", Monkey::parse_operation))?;
+        let test_divisor = lines.next_line(|s| s.field("Test", |s| {
+            s.expect("divisible by ")?;
+            match s.consumed(|s| s.unsigned("a divisor"))? {
+                (divisor, 0) => Err(s.error(divisor, "cannot test for divisibility by 0")),
+                (_, divisor) => Ok(divisor),
+            }
+        }))?;
+        let mut target = |label: &str| lines.next_line(|s| s.field(label, |s| {
+            s.expect("throw to monkey ")?;
+            s.unsigned("a monkey number")
+        }));
+        Ok(Monkey {
+            items: VecDeque::from(items),
+            operation,
+            operation_line: 3,
+            test_divisor,
+            true_target: target("If true")?,
+            false_target: target("If false")?,
+            item_count: 0,
+        })
+    }
+
+    /// An operation like `new = old * 19`.
+    fn parse_operation(s: &mut Scanner) -> Result<Box<dyn Fn(u64) -> Option<u64>>> {
+        s.expect("new = old ")?;
+        let fun = match s.word() {
+            "*" => u64::checked_mul,
+            "+" => u64::checked_add,
+            operation => return Err(s.error(operation, "unmatched operation")),
+        };
+        s.expect(" ")?;
+        let option = if s.accept("old") { None } else { Some(s.unsigned::<u64>("an operand")?) };
+        Ok(Box::new(move |old: u64| {
+            fun(old, option.unwrap_or(old))
+        }))
+    }
+
+    /// The worry level of the next item after inspecting it and the monkey it is thrown to, `None`
+    /// once the monkey holds no more items.
+    pub fn throw(&mut self, modulus: &u64, decrease_worry_level: bool) -> Result<Option<(u64, u32)>> {
+        let Some(worry_level) = self.items.pop_front() else {
+            return Ok(None);
+        };
+        self.item_count += 1;
+        let mut worry_level = (self.operation)(worry_level).ok_or_else(|| {
+            Error::new("", format!("worry level {worry_level} does not fit into 64 bits after the operation")).on_line(self.operation_line)
+        })? % modulus;
+        if decrease_worry_level {
+            worry_level /= 3;
+        }
+        let target = if Integer::is_multiple_of(&worry_level, &self.test_divisor) {
+            self.true_target
+        } else {
+            self.false_target
+        };
+        Ok(Some((worry_level, target)))
+    }
+}
+
+pub fn parse_monkeys(input: &str) -> Result<Vec<Monkey>> {
+    let mut monkeys = Vec::new();
+    let mut first_lines = Vec::new();
+    for (first_line, block) in parse::blocks(input) {
+        let mut monkey = Monkey::new(block).map_err(|e| e.in_block(first_line))?;
+        monkey.operation_line += first_line - 1;
+        monkeys.push(monkey);
+        first_lines.push(first_line);
+    }
+    for (idx, (monkey, first_line)) in monkeys.iter().zip(first_lines).enumerate() {
+        for (target, line) in [(monkey.true_target, first_line + 4), (monkey.false_target, first_line + 5)] {
+            if target as usize >= monkeys.len() {
+                return Err(Error::new("", format!("there is no monkey {target}")).on_line(line));
+            }
+            // it would keep throwing the item to itself forever
+            if target as usize == idx {
+                return Err(Error::new("", format!("monkey {target} throws to itself")).on_line(line));
+            }
+        }
+    }
+    Ok(monkeys)
+}
+
+
+pub fn round(monkeys: &mut [Monkey], decrease_worry_level: bool, modulus: &u64) -> Result<()> {
+    for i in 0..monkeys.len() {
+        while let Some((item, target)) = monkeys[i].throw(modulus, decrease_worry_level)? {
+            // println!("Monkey {} throws {} at monkey {}", i, item, target);
+            monkeys[target as usize].items.push_back(item);
+        }
+    }
+    Ok(())
+}
+
+pub fn monkey_business(input: &str, rounds: u32, decrease_worry_level: bool) -> Result<u64> {
+    let mut monkeys = parse_monkeys(input)?;
+    let modulus = modulus(&monkeys)?;
+    for _ in 1..=rounds {
+        round(&mut monkeys, decrease_worry_level, &modulus)?;
+    }
+    // dbg!(&monkeys.iter().map(|m| { m.item_count }).collect::<Vec<u64>>());
+    Ok(business(&monkeys))
+}
+
+/// The product of all test divisors. Worry levels can be taken modulo it without changing where
+/// any monkey throws them.
+fn modulus(monkeys: &[Monkey]) -> Result<u64> {
+    monkeys.iter().try_fold(1_u64, |product, monkey| product.checked_mul(monkey.test_divisor))
+        .ok_or_else(|| Error::new("", "the product of the test divisors does not fit into 64 bits"))
+}
+
+/// The product of the two highest numbers of inspected items.
+fn business(monkeys: &[Monkey]) -> u64 {
+    monkeys.iter().map(|m| { m.item_count }).sorted().rev().take(2).product()
+}
+
+/// Plays rounds of keep away a few at a time to look at what the monkeys hold.
+struct Explorer {
+    monkeys: Vec<Monkey>,
+    modulus: u64,
+    rounds: u32,
+    decrease_worry_level: bool,
+}
+
+impl Session for Explorer {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("round [N]", "play N rounds, 1 by default"),
+            ("inventory", "the items each monkey holds, and how many it inspected"),
+            ("relief <on|off>", "whether worry levels are divided by 3 after inspection, on in part 1"),
+        ]
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> std::result::Result<String, String> {
+        match command {
+            "round" => {
+                for _ in 0..argument(args, "N", Some(1))? {
+                    round(&mut self.monkeys, self.decrease_worry_level, &self.modulus).map_err(|e| e.to_string())?;
+                    self.rounds += 1;
+                }
+                Ok(format!("after round {}, monkey business is {}", self.rounds, business(&self.monkeys)))
+            }
+            "inventory" => Ok(self.monkeys.iter().enumerate().map(|(idx, monkey)| {
+                let items = if monkey.items.is_empty() { "nothing".to_string() } else { monkey.items.iter().join(", ") };
+                format!("Monkey {idx}: {items} (inspected {} items)\n", monkey.item_count)
+            }).collect()),
+            _ => {
+                self.decrease_worry_level = match argument::<String>(args, "on or off", None)?.as_str() {
+                    "on" => true,
+                    "off" => false,
+                    relief => return Err(format!("expected on or off, got {relief:?}")),
+                };
+                Ok(format!("worry levels are {} divided by 3", if self.decrease_worry_level { "now" } else { "no longer" }))
+            }
+        }
+    }
+}
+
+/// Every monkey that is not described by exactly six lines, which parsing relies on.
+pub fn validate(input: &str) -> Vec<Error> {
+    let mut violations = Vec::new();
+    // the first line of the current monkey, and its number of lines
+    let mut monkey: Option<(usize, &str, usize)> = None;
+    for (line_idx, line) in input.lines().map(Some).chain([None]).enumerate() {
+        match (line.filter(|l| !l.is_empty()), monkey) {
+            (Some(line), None) => monkey = Some((line_idx, line, 1)),
+            (Some(_), Some((first_idx, first, count))) => monkey = Some((first_idx, first, count + 1)),
+            (None, Some((first_idx, first, count))) => {
+                if count != 6 {
+                    let message = format!("expected 6 lines describing the monkey, found {count}");
+                    violations.push(Error::new(first, message).on_line(first_idx + 1));
+                }
+                monkey = None;
+            }
+            (None, None) => {}
+        }
+    }
+    violations
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(monkey_business(input, 20, true)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        Some(monkey_business(input, 10_000, false).map(Answer::from))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(parse_monkeys(input).map(drop))
+    }
+
+    fn validate(&self, input: &str) -> Option<Vec<Error>> {
+        Some(validate(input))
+    }
+
+    fn explore(&self, input: &str) -> Option<Result<Box<dyn Session>>> {
+        Some(parse_monkeys(input).and_then(|monkeys| {
+            let modulus = modulus(&monkeys)?;
+            Ok(Box::new(Explorer { monkeys, modulus, rounds: 0, decrease_worry_level: true }) as Box<dyn Session>)
+        }))
+    }
+}
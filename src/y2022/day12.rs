@@ -0,0 +1,214 @@
+use std::cmp::min;
+
+use crate::error::{Error, Result};
+use crate::geom::Point;
+use crate::grid::Grid;
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::geom::Point;
+
+    const SAMPLE_INPUT: &str = indoc! {"
+        Sabqponm
+        abcryxxl
+        accszExk
+        acctuvwj
+        abdefghi
+    "};
+
+    #[test]
+    fn parses_input_matrix() {
+        let height_map = super::parse_input(SAMPLE_INPUT).unwrap();
+        // dbg!(&height_map);
+        assert_eq!(height_map.start, Point::new(0, 0));
+        assert_eq!(height_map.end, Point::new(5, 2));
+        assert_eq!(height_map.map.dimensions(), (5, 8));
+        assert_eq!(height_map.map.rows(), 5);
+        assert_eq!(height_map.map.columns(), 8);
+        assert_eq!(height_map.map[Point::new(0, 0)], "abcdefghijklmnopqrstuvwxyz".find('a').unwrap());
+        assert_eq!(height_map.map[Point::new(1, 0)], "abcdefghijklmnopqrstuvwxyz".find('a').unwrap());
+        assert_eq!(height_map.map[Point::new(2, 0)], "abcdefghijklmnopqrstuvwxyz".find('b').unwrap());
+
+        assert_eq!(height_map.map[Point::new(7, 0)], "abcdefghijklmnopqrstuvwxyz".find('m').unwrap());
+        assert_eq!(height_map.map[Point::new(5, 2)], "abcdefghijklmnopqrstuvwxyz".find('z').unwrap());
+        assert_eq!(height_map.map[Point::new(7, 4)], "abcdefghijklmnopqrstuvwxyz".find('i').unwrap());
+    }
+
+    #[test]
+    fn shortest_path() {
+        let mut height_map = super::parse_input(SAMPLE_INPUT).unwrap();
+        assert_eq!(height_map.shortest_path_form_start(Point::new(1, 0)), 1);
+        assert_eq!(height_map.shortest_path_form_start(Point::new(1, 1)), 2);
+        assert_eq!(height_map.shortest_path_form_start(height_map.end), 31);
+    }
+
+    #[test]
+    pub fn shortest_path_anywhere() {
+        let mut height_map = super::parse_input(SAMPLE_INPUT).unwrap();
+        assert_eq!(height_map.shortest_path_anywhere(height_map.end), 29);
+    }
+
+    #[test]
+    fn reports_invalid_height_maps() {
+        let error = super::parse_input("Sab\nc?E\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "?"));
+        let error = super::parse_input("Sab\ncE\n").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (2, "expected 3 cells like in the first row"));
+        let error = super::parse_input("Sab\ncde\n").unwrap_err();
+        assert_eq!(error.message, "no best signal position E");
+        assert!(super::steps_to_signal("SbE\n").is_err());
+    }
+
+    #[test]
+    fn validates_rows_and_squares() {
+        assert_eq!(super::validate(SAMPLE_INPUT), vec![]);
+        let violations = super::validate("Sab\nc?\nd\nabc\n");
+        let found: Vec<_> = violations.iter().map(|e| (e.line, e.column, e.message.as_str())).collect();
+        assert_eq!(found, vec![
+            (2, 1, "expected 3 squares like in the first row"),
+            (2, 2, "unknown square"),
+            (3, 1, "expected 3 squares like in the first row"),
+            (1, 1, "no best signal position E"),
+        ]);
+    }
+}
+
+pub fn steps_to_signal(input: &str) -> Result<u32> {
+    let mut height_map = parse_input(input)?;
+    reachable(height_map.shortest_path_form_start(height_map.end))
+}
+
+pub fn shortest_hike(input: &str) -> Result<u32> {
+    let mut height_map = parse_input(input)?;
+    reachable(height_map.shortest_path_anywhere(height_map.end))
+}
+
+fn reachable(steps: u32) -> Result<u32> {
+    if steps == u32::MAX {
+        return Err(Error::new("", "the best signal position cannot be reached"));
+    }
+    Ok(steps)
+}
+
+#[derive(Debug)]
+pub struct HeightMap {
+    pub start: Point<usize>,
+    pub end: Point<usize>,
+    map: Grid<usize>,
+    costs: Grid<u32>,
+}
+
+impl HeightMap {
+    pub fn shortest_path_form_start(&mut self, target: Point<usize>) -> u32 {
+        self.shortest_path(target)
+    }
+
+    fn shortest_path_anywhere(&mut self, target: Point<usize>) -> u32 {
+        for position in self.map.points() {
+            if self.height(position) == 0 {
+                self.costs[position] = 0;
+            }
+        }
+        self.shortest_path(target)
+    }
+    fn shortest_path(&mut self, target: Point<usize>) -> u32 {
+        self.costs[self.start] = 0;
+        loop {
+            let mut changed = false;
+            for position in self.map.points() {
+                let new_cost = self.compute_cost(position);
+                if new_cost != self.costs[position] {
+                    changed = true;
+                    self.costs[position] = new_cost;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        self.costs[target]
+    }
+    /// The cost of `position`, one more than that of a neighbour it can be reached from if cheaper.
+    fn compute_cost(&self, position: Point<usize>) -> u32 {
+        self.map.neighbours4(position)
+            .filter(|&neighbour| self.costs[neighbour] < u32::MAX && self.height(neighbour) >= self.height(position) - 1)
+            .map(|neighbour| 1 + self.costs[neighbour])
+            .fold(self.costs[position], min)
+    }
+
+    fn height(&self, position: Point<usize>) -> isize {
+        self.map[position] as isize
+    }
+}
+
+
+pub fn parse_input(input: &str) -> Result<HeightMap> {
+    let mut start = None;
+    let mut end = None;
+    let map = Grid::parse(input, |position, chr| {
+        let height = match chr {
+            'S' => {
+                start = Some(position);
+                'a'
+            }
+            'E' => {
+                end = Some(position);
+                'z'
+            }
+            _ => chr,
+        };
+        "abcdefghijklmnopqrstuvwxyz".find(height).ok_or_else(|| "unknown square".to_string())
+    })?;
+    let costs = Grid::new(map.rows(), map.columns(), u32::MAX);
+    Ok(HeightMap {
+        start: start.ok_or_else(|| Error::new("", "no starting position S"))?,
+        end: end.ok_or_else(|| Error::new("", "no best signal position E"))?,
+        map,
+        costs,
+    })
+}
+
+/// Every row that is not as long as the first one, every unknown square, and a missing start or end.
+pub fn validate(input: &str) -> Vec<Error> {
+    let cols = input.lines().next().map_or(0, |l| l.chars().count());
+    let mut violations = Vec::new();
+    for (row, line) in input.lines().enumerate() {
+        let error = |part: &str, message: String| Error::in_line(line, part, message).on_line(row + 1);
+        if line.chars().count() != cols {
+            violations.push(error(line, format!("expected {cols} squares like in the first row")));
+        }
+        for (byte_idx, chr) in line.char_indices().filter(|&(_, chr)| !chr.is_ascii_lowercase() && chr != 'S' && chr != 'E') {
+            violations.push(error(&line[byte_idx..byte_idx + chr.len_utf8()], "unknown square".to_string()));
+        }
+    }
+    if !input.contains('S') {
+        violations.push(Error::new("", "no starting position S"));
+    }
+    if !input.contains('E') {
+        violations.push(Error::new("", "no best signal position E"));
+    }
+    violations
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(steps_to_signal(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        Some(shortest_hike(input).map(Answer::from))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(parse_input(input).map(drop))
+    }
+
+    fn validate(&self, input: &str) -> Option<Vec<Error>> {
+        Some(validate(input))
+    }
+}
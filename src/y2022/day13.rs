@@ -0,0 +1,312 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+
+use crate::error::{Error, Result};
+use crate::parse::{self, Scanner};
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const SAMPLE_INPUT: &str = indoc! {"
+        [1,1,3,1,1]
+        [1,1,5,1,1]
+
+        [[1],[2,3,4]]
+        [[1],4]
+
+        [9]
+        [[8,7,6]]
+
+        [[4,4],4,4]
+        [[4,4],4,4,4]
+
+        [7,7,7,7]
+        [7,7,7]
+
+        []
+        [3]
+
+        [[[]]]
+        [[]]
+
+        [1,[2,[3,[4,[5,6,7]]]],8,9]
+        [1,[2,[3,[4,[5,6,0]]]],8,9]
+    "};
+
+    #[test]
+    fn parses_int_only_input() {
+        let packet = Packet::new(SAMPLE_INPUT.lines().next().unwrap()).unwrap();
+        let vec1 = vec![Packet::Int(1), Packet::Int(1), Packet::Int(3), Packet::Int(1), Packet::Int(1)];
+        assert_eq!(packet, Packet::List(vec1));
+    }
+
+    #[test]
+    fn parses_nested_list() {
+        let packet = Packet::new(SAMPLE_INPUT.lines().nth(3).unwrap()).unwrap();
+        let vec1 = vec![Packet::List(vec![Packet::Int(1)]), Packet::List(vec![Packet::Int(2), Packet::Int(3), Packet::Int(4)])];
+        assert_eq!(packet, Packet::List(vec1));
+    }
+
+    #[test]
+    fn parses_deeply_nested_list() {
+        let packet = Packet::new("[1,[2,[3,[4,[5,6,7]]]],8,9]").unwrap();
+        let p = Packet::List(vec![
+            Packet::Int(1),
+            Packet::List(vec![
+                Packet::Int(2),
+                Packet::List(vec![
+                    Packet::Int(3),
+                    Packet::List(vec![
+                        Packet::Int(4),
+                        Packet::List(vec![
+                            Packet::Int(5),
+                            Packet::Int(6),
+                            Packet::Int(7),
+                        ]),
+                    ]),
+                ]),
+            ]),
+            Packet::Int(8),
+            Packet::Int(9),
+        ]);
+        assert_eq!(packet, p);
+    }
+
+    #[test]
+    fn compares_int_lists() {
+        assert!(Packet::new("[1,1,3,1,1]").unwrap() < Packet::new("[1,1,5,1,1]").unwrap());
+    }
+
+    #[test]
+    fn compares_mixed_lists() {
+        assert!(Packet::new("[[1],[2,3,4]]").unwrap() < Packet::new("[[1],4]").unwrap());
+        assert!(Packet::new("[[1],4]").unwrap() > Packet::new("[[1],[2,3,4]]").unwrap());
+    }
+
+    #[test]
+    fn compare_mixed_lists_2() {
+        assert!(Packet::new("[9]").unwrap() > Packet::new("[[8,7,6]]").unwrap());
+        assert!(Packet::new("[[8,7,6]]").unwrap() < Packet::new("[9]").unwrap());
+    }
+
+    #[test]
+    fn sums_correct_order_indices() {
+        assert_eq!(part1(SAMPLE_INPUT), Ok(13));
+    }
+
+    #[test]
+    fn computes_decoder_key() {
+        let result = part2(SAMPLE_INPUT);
+        assert_eq!(result, Ok(140));
+    }
+
+    #[test]
+    fn reports_invalid_packets() {
+        let error = Packet::new("[1,[2,3]").unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (9, "expected \"]\""));
+        let error = Packet::new("1,[2]").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (1, "1"));
+        let error = part1("[1]\n[2]\n\n[3]\n[4]]]\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (5, 4, "]]"));
+        let error = part1("[1]\n[2]\n\n[3]\n").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (4, "packet without a partner"));
+        let error = Packet::new(&"[".repeat(100_000)).unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (102, "packets may only nest 100 lists"));
+    }
+
+    #[test]
+    fn orders_packets_the_puzzle_considers_equal() {
+        let (int, list) = (Packet::new("[1]").unwrap(), Packet::new("[[1]]").unwrap());
+        assert_eq!(int.puzzle_cmp(&list), Ordering::Equal);
+        assert!(int < list);
+    }
+
+    fn packet() -> impl Strategy<Value=Packet> {
+        (0..4_u32).prop_map(Packet::Int)
+            .prop_recursive(4, 24, 4, |inner| prop::collection::vec(inner, 0..4).prop_map(Packet::List))
+    }
+
+    proptest! {
+        #[test]
+        fn ord_is_consistent_with_eq(left in packet(), right in packet()) {
+            prop_assert_eq!(left.cmp(&right) == Ordering::Equal, left == right);
+            prop_assert_eq!(left.cmp(&right), right.cmp(&left).reverse());
+        }
+
+        #[test]
+        fn ord_is_transitive(a in packet(), b in packet(), c in packet()) {
+            // sorted by a transitive order, every pair is in order, not just the neighbours
+            let in_order = |[a, b, c]: &[Packet; 3], cmp: fn(&Packet, &Packet) -> Ordering| {
+                cmp(a, b).is_le() && cmp(b, c).is_le() && cmp(a, c).is_le()
+            };
+            let mut sorted = [a, b, c];
+            sorted.sort_by(Packet::puzzle_cmp);
+            prop_assert!(in_order(&sorted, Packet::puzzle_cmp), "{:?}", sorted);
+            sorted.sort();
+            prop_assert!(in_order(&sorted, Packet::cmp), "{:?}", sorted);
+        }
+    }
+}
+
+pub fn part2(input: &str) -> Result<u32> {
+    let mut packets = parse(input)?;
+    let div1 = Packet::List(vec![Packet::List(vec![Packet::Int(2)])]);
+    packets.push(div1.clone());
+    let div2 = Packet::List(vec![Packet::List(vec![Packet::Int(6)])]);
+    packets.push(div2.clone());
+    packets.sort();
+    Ok(packets.iter().enumerate().map(|(idx, packet)| {
+        if packet == &div1 || packet == &div2 {
+            (idx + 1) as u32
+        } else {
+            1_u32
+        }
+    }).product())
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let packets = parse(input)?;
+    if packets.len() % 2 != 0 {
+        let last_line = input.lines().enumerate().filter(|(_, l)| { !l.is_empty() }).last().map_or(1, |(idx, _)| idx + 1);
+        return Err(Error::new("", "packet without a partner").on_line(last_line));
+    }
+    Ok(packets.chunks(2)
+        .enumerate()
+        .map(|(idx, chunk)| {
+            if chunk[0].puzzle_cmp(&chunk[1]).is_lt() {
+                idx + 1
+            } else {
+                0
+            }
+        }).sum::<usize>())
+}
+
+pub fn parse(input: &str) -> Result<Vec<Packet>> {
+    input.lines()
+        .enumerate()
+        .filter(|(_, l)| { !l.is_empty() })
+        .map(|(line_idx, line)| Packet::new(line).map_err(|e| e.on_line(line_idx + 1)))
+        .collect()
+}
+
+#[derive(PartialEq, Eq, Clone)]
+pub enum Packet {
+    List(Vec<Packet>),
+    Int(u32),
+}
+
+impl Debug for Packet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Packet::List(l) => {
+                f.debug_list().entries(l.iter()).finish()
+            }
+            Packet::Int(i) => {
+                write!(f, "{i}")
+            }
+        }
+    }
+}
+
+/// The order of the puzzle, made consistent with `Eq` by ordering packets the puzzle considers
+/// equal, like `1` and `[1]`, by their structure.
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.puzzle_cmp(other).then_with(|| self.structure_cmp(other))
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Packet) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+
+impl Packet {
+    /// The order of the puzzle, in which an integer equals the list of just that integer.
+    fn puzzle_cmp(&self, other: &Packet) -> Ordering {
+        match (self, other) {
+            (Packet::Int(self_int), Packet::Int(other_int)) => {
+                self_int.cmp(other_int)
+            }
+            (Packet::List(self_list), Packet::List(other_list)) => {
+                self_list.iter().zip(other_list)
+                    .map(|(left, right)| left.puzzle_cmp(right))
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or_else(|| self_list.len().cmp(&other_list.len()))
+            }
+            (Packet::List(_), Packet::Int(other_int)) => {
+                self.puzzle_cmp(&Packet::List(vec![Packet::Int(*other_int)]))
+            }
+            (Packet::Int(self_int), Packet::List(_)) => {
+                Packet::List(vec![Packet::Int(*self_int)]).puzzle_cmp(other)
+            }
+        }
+    }
+
+    /// An order that is only `Equal` for equal packets, with integers before lists.
+    fn structure_cmp(&self, other: &Packet) -> Ordering {
+        match (self, other) {
+            (Packet::Int(self_int), Packet::Int(other_int)) => self_int.cmp(other_int),
+            (Packet::List(self_list), Packet::List(other_list)) => {
+                self_list.iter().zip(other_list)
+                    .map(|(left, right)| left.structure_cmp(right))
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or_else(|| self_list.len().cmp(&other_list.len()))
+            }
+            (Packet::Int(_), Packet::List(_)) => Ordering::Less,
+            (Packet::List(_), Packet::Int(_)) => Ordering::Greater,
+        }
+    }
+
+    pub fn new(input: &str) -> Result<Packet> {
+        parse::line(input, |s| {
+            s.expect("[")?;
+            Packet::parse_list(s, 1)
+        })
+    }
+
+    /// The rest of a list whose opening bracket has been read, nested in `depth` lists.
+    fn parse_list(s: &mut Scanner, depth: usize) -> Result<Packet> {
+        if depth > MAX_DEPTH {
+            return Err(s.error(s.rest(), format!("packets may only nest {MAX_DEPTH} lists")));
+        }
+        if s.accept("]") {
+            return Ok(Packet::List(Vec::new()));
+        }
+        let packets = s.list(",", |s| {
+            if s.accept("[") {
+                Packet::parse_list(s, depth + 1)
+            } else {
+                s.unsigned("a number or a list").map(Packet::Int)
+            }
+        })?;
+        s.expect("]")?;
+        Ok(Packet::List(packets))
+    }
+}
+
+/// How deep packets may nest lists, keeping the parser from running out of stack.
+const MAX_DEPTH: usize = 100;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        Some(part2(input).map(Answer::from))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(parse(input).map(drop))
+    }
+}
@@ -0,0 +1,462 @@
+use std::cmp::{max, min};
+use std::fmt::{Display, Formatter, Write};
+use std::ops::{RangeInclusive};
+use itertools::Itertools;
+use crate::error::{Error, Result};
+use crate::geom::{Direction, Point};
+use crate::grid::Grid;
+use crate::parse;
+use crate::repl::{argument, Session};
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use proptest::prelude::*;
+    use super::*;
+
+    const SAMPLE_INPUT: &str = indoc! {"
+        498,4 -> 498,6 -> 496,6
+        503,4 -> 502,4 -> 502,9 -> 494,9
+    "};
+
+    #[test]
+    fn parses_input() {
+        let map = parse_scan(SAMPLE_INPUT).unwrap();
+        assert_eq!((494..=503, 0..=9), map.dim());
+    }
+
+    #[test]
+    fn displays_nicely() {
+        const EXPECTED: &str = "  4     5  5
+  9     0  0
+  4     0  3
+0 ......+...
+1 ..........
+2 ..........
+3 ..........
+4 ....#...##
+5 ....#...#.
+6 ..###...#.
+7 ........#.
+8 ........#.
+9 #########.
+";
+        let map = parse_scan(SAMPLE_INPUT).unwrap();
+        assert_eq!(EXPECTED, format!("{map}"));
+    }
+
+    #[test]
+    fn displays_labels_of_different_lengths_and_the_source() {
+        let map = parse_scan("95,1 -> 105,1").unwrap();
+        let rendered = map.to_string();
+        let lines: Vec<&str> = rendered.lines().collect();
+        let label = |x: usize| lines[..3].iter().map(|line| line.chars().nth(2 + x - 95).unwrap()).collect::<String>();
+        assert_eq!(label(95), " 95");
+        assert_eq!(label(100), "100");
+        assert_eq!(label(105), "   ");
+        assert_eq!(label(500), "500");
+        assert_eq!(lines[3], format!("0 {}+", ".".repeat(405)));
+        assert_eq!(lines[4], format!("1 {}{}", "#".repeat(11), ".".repeat(395)));
+        assert_eq!(lines.len(), 5);
+        assert!(Day14.explore("95,1 -> 105,1").unwrap().unwrap().execute("show", &[]).is_ok());
+    }
+
+    #[test]
+    fn test_sand_falls_down_vertically() {
+        let mut map = parse_scan(SAMPLE_INPUT).unwrap();
+        map.drop_sand();
+
+        const EXPECTED: &str = "  4     5  5
+  9     0  0
+  4     0  3
+0 ......+...
+1 ..........
+2 ..........
+3 ..........
+4 ....#...##
+5 ....#...#.
+6 ..###...#.
+7 ........#.
+8 ......o.#.
+9 #########.
+";
+        assert_eq!(EXPECTED, format!("{map}"));
+    }
+
+    #[test]
+    fn test_sand_falls_down_vertically_then_left() {
+        let mut map = parse_scan(SAMPLE_INPUT).unwrap();
+        map.drop_sand();
+        map.drop_sand();
+
+        const EXPECTED: &str = "  4     5  5
+  9     0  0
+  4     0  3
+0 ......+...
+1 ..........
+2 ..........
+3 ..........
+4 ....#...##
+5 ....#...#.
+6 ..###...#.
+7 ........#.
+8 .....oo.#.
+9 #########.
+";
+        assert_eq!(EXPECTED, format!("{map}"));
+    }
+
+    #[test]
+    fn test_sand_falls_down_vertically_then_left_then_right() {
+        let mut map = parse_scan(SAMPLE_INPUT).unwrap();
+        map.drop_sand();
+        map.drop_sand();
+        map.drop_sand();
+
+        const EXPECTED: &str = "  4     5  5
+  9     0  0
+  4     0  3
+0 ......+...
+1 ..........
+2 ..........
+3 ..........
+4 ....#...##
+5 ....#...#.
+6 ..###...#.
+7 ........#.
+8 .....ooo#.
+9 #########.
+";
+        assert_eq!(EXPECTED, format!("{map}"));
+    }
+
+    #[test]
+    fn test_sand_24() {
+        let mut map = parse_scan(SAMPLE_INPUT).unwrap();
+        for _ in 0..24 {
+            map.drop_sand();
+        }
+
+        const EXPECTED: &str = "  4     5  5
+  9     0  0
+  4     0  3
+0 ......+...
+1 ..........
+2 ......o...
+3 .....ooo..
+4 ....#ooo##
+5 ...o#ooo#.
+6 ..###ooo#.
+7 ....oooo#.
+8 .o.ooooo#.
+9 #########.
+";
+        assert_eq!(EXPECTED, format!("{map}"));
+    }
+
+    #[test]
+    fn test_sand_drops_out_at_bottom() {
+        let mut map = parse_scan(SAMPLE_INPUT).unwrap();
+        for _ in 0..250 {
+            map.drop_sand();
+        }
+
+        const EXPECTED: &str = "  4     5  5
+  9     0  0
+  4     0  3
+0 ......+...
+1 ..........
+2 ......o...
+3 .....ooo..
+4 ....#ooo##
+5 ...o#ooo#.
+6 ..###ooo#.
+7 ....oooo#.
+8 .o.ooooo#.
+9 #########.
+";
+        assert_eq!(EXPECTED, format!("{map}"));
+    }
+
+    #[test]
+    fn computes_units_until_full() {
+        let mut map = parse_scan(SAMPLE_INPUT).unwrap();
+        assert_eq!(24, map.sand_until_full());
+    }
+
+    #[test]
+    fn explores_falling_sand() {
+        let mut session = Day14.explore(SAMPLE_INPUT).unwrap().unwrap();
+        assert_eq!(session.execute("drop", &[]), Ok("1 unit of sand at rest".to_string()));
+        assert_eq!(session.execute("drop", &["23"]), Ok("24 units of sand at rest".to_string()));
+        assert!(session.execute("show", &[]).unwrap().ends_with("8 .o.ooooo#.\n9 #########.\n"));
+        assert_eq!(session.execute("drop", &["5"]), Ok("sand falls out at the bottom, 24 units of sand at rest".to_string()));
+    }
+
+    #[test]
+    fn reports_invalid_scans() {
+        let error = parse_scan("498,4 -> 498,6\n503,4 -> 502;4\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 10, "502;4"));
+        let error = parse_scan("498,4 -> 500,6\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.message.as_str()), (1, 10, "rock paths have to be horizontal or vertical"));
+        assert!(parse_scan("").is_err());
+    }
+
+    #[test]
+    fn reports_huge_scans() {
+        let error = parse_scan("0,0 -> 18446744073709551615,0").err().unwrap();
+        assert_eq!(error.message, "the rock paths span more than 16777216 positions");
+    }
+
+    #[test]
+    fn stops_when_the_source_is_blocked() {
+        assert_eq!(part1("499,1 -> 501,1"), Ok(1));
+    }
+
+    #[test]
+    fn fills_wide_caves() {
+        assert_eq!(part1("400,100 -> 600,100"), Ok(10000));
+    }
+
+    /// A horizontal or vertical rock path of a single segment, within a few units of the source.
+    fn rock_path(y: std::ops::Range<usize>) -> impl Strategy<Value=String> {
+        (490..=510_usize, y, any::<bool>(), 0..6_usize).prop_map(|(x, y, horizontal, len)| {
+            let (to_x, to_y) = if horizontal { (x + len, y) } else { (x, y + len) };
+            format!("{x},{y} -> {to_x},{to_y}")
+        })
+    }
+
+    proptest! {
+        /// The added rock path lies below all the others on purpose: sand that came to rest before
+        /// never reaches it, so it can only catch sand that used to fall into the abyss. Rocks added
+        /// anywhere else can deflect sand off a ledge it used to pile up on, so part 1 is not
+        /// monotone in the rocks in general.
+        #[test]
+        fn rocks_below_all_others_never_decrease_sand(paths in prop::collection::vec(rock_path(1..12), 1..8),
+                                                      x in 480..=510_usize, len in 0..30_usize, gap in 1..4_usize) {
+            let scan = paths.join("\n");
+            let y = parse_scan(&scan).unwrap().dim().1.end() + gap;
+            let below = format!("{scan}\n{x},{y} -> {},{y}", x + len);
+            prop_assert!(part1(&below).unwrap() >= part1(&scan).unwrap(), "{}", below);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Material {
+    Air,
+    Rock,
+    Sand,
+}
+
+/// Where the sand comes from.
+const SOURCE: Point<usize> = Point::new(500, 0);
+
+/// The rocks and the sand at rest, with y growing downwards.
+pub struct Map {
+    /// From one left of the left-most rock to one right of the right-most rock or the source.
+    map: Grid<Material>,
+    /// The x coordinate of the first column of `map`.
+    left: usize,
+    /// The y coordinate of the lowest rock, below which sand falls out.
+    lowest_rock: usize,
+}
+
+impl Map {
+    /// What is at `point`, air beyond the rocks.
+    fn get(&self, point: Point<usize>) -> Material {
+        point.x.checked_sub(self.left).and_then(|x| self.map.get(Point::new(x, point.y))).copied().unwrap_or(Material::Air)
+    }
+
+    fn set(&mut self, point: Point<usize>, material: Material) {
+        self.map[Point::new(point.x - self.left, point.y)] = material;
+    }
+
+    pub fn dim(&self) -> (RangeInclusive<usize>, RangeInclusive<usize>) {
+        let filled = || self.map.points().filter(|&point| self.map[point] != Material::Air);
+        let x_range = match filled().map(|point| { point.x + self.left }).minmax() {
+            itertools::MinMaxResult::NoElements => panic!("empty map"),
+            itertools::MinMaxResult::OneElement(el) => el..=el,
+            itertools::MinMaxResult::MinMax(min, max) => min..=max,
+        };
+        let y_range = match filled().map(|point| { point.y }).minmax() {
+            itertools::MinMaxResult::NoElements => panic!("empty map"),
+            itertools::MinMaxResult::OneElement(el) => min(0, el)..=el,
+            itertools::MinMaxResult::MinMax(min_rock, max) => min(0, min_rock)..=max,
+        };
+        (x_range, y_range)
+    }
+
+    pub fn sand_until_full(&mut self) -> u32 {
+        let mut sand = 0;
+        while self.drop_sand() {
+            sand += 1;
+        }
+        sand
+    }
+
+    pub fn drop_sand(&mut self) -> bool {
+        // determine where sand will come to rest by starting at the origin, and applying the rules]
+        // until it comes to rest
+        let mut sand_location = SOURCE;
+        if self.get(sand_location) != Material::Air {
+            // the sand piled up to the source
+            return false;
+        }
+        loop {
+            // A unit of sand always falls down one step if possible.
+            if sand_location.y + 1 > self.lowest_rock {
+                // sand falls out the bottom
+                return false
+            }
+            // If the tile immediately below is blocked (by rock or sand), the unit of sand
+            // attempts to instead move diagonally one step down and to the left. If that tile is
+            // blocked, the unit of sand attempts to instead move diagonally one step down and to
+            // the right.
+            let next = [Direction::S, Direction::SW, Direction::SE].into_iter()
+                .map(|direction| sand_location.step(direction))
+                .find(|next| next.is_none_or(|next| self.get(next) == Material::Air));
+            match next {
+                Some(Some(next)) => sand_location = next,
+                // left of x = 0 there are no rocks to land on
+                Some(None) => return false,
+                // Sand keeps moving as long as it is able to do so, at each step trying to move
+                // down, then down-left, then down-right. If all three possible destinations are
+                // blocked, the unit of sand comes to rest and no longer moves
+                None => break,
+            }
+        }
+        // then update the map at the location to `Sand`
+        self.set(sand_location, Material::Sand);
+        true
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (x_range, y_range) = self.dim();
+        // always show where the sand comes from
+        let x_range = min(*x_range.start(), SOURCE.x)..=max(*x_range.end(), SOURCE.x);
+        let y_digits = y_range.end().to_string().len();
+        let x_digits = x_range.end().to_string().len();
+        // the x labels are written top down, right-aligned like numbers
+        let labels: Vec<Vec<char>> = x_range.clone()
+            .map(|x| if x == *x_range.start() || x % 10 == 0 || x == *x_range.end() {
+                format!("{x:>x_digits$}").chars().collect()
+            } else {
+                vec![' '; x_digits]
+            })
+            .collect();
+        for digit in 0..x_digits {
+            f.write_str(" ".repeat(y_digits + 1).as_str())?;
+            for label in &labels {
+                f.write_char(label[digit])?;
+            }
+            writeln!(f)?;
+        }
+        for y in y_range {
+            f.write_fmt(format_args!("{y:y_digits$} "))?;
+            for x in x_range.clone() {
+                let point = Point::new(x, y);
+                if self.get(point) == Material::Rock {
+                    f.write_char('#')?;
+                } else if self.get(point) == Material::Sand {
+                    f.write_char('o')?;
+                } else if point == SOURCE {
+                    f.write_char('+')?;
+                } else {
+                    f.write_char('.')?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// The most cells a map may have, far more than any puzzle input needs.
+const MAX_MAP_SIZE: usize = 1 << 24;
+
+pub fn parse_scan(input: &str) -> Result<Map> {
+    let mut segments: Vec<(Point<usize>, Point<usize>)> = Vec::new();
+    for (line_idx, line) in input.lines().enumerate() {
+        let points = parse::line(line, |s| s.list(" -> ", |s| s.consumed(|s| s.point::<usize>())))
+            .map_err(|e| e.on_line(line_idx + 1))?;
+        for ((_, from), (to_str, to)) in points.into_iter().tuple_windows() {
+            if from.x != to.x && from.y != to.y {
+                return Err(Error::in_line(line, to_str, "rock paths have to be horizontal or vertical").on_line(line_idx + 1));
+            }
+            segments.push((from, to));
+        }
+    }
+    let points = || segments.iter().flat_map(|&(from, to)| [from, to]);
+    let min_x = points().map(|p| { p.x }).min().ok_or_else(|| Error::new("", "no rock paths"))?;
+    let max_x = points().map(|p| { p.x }).max().unwrap();
+    let max_y = points().map(|p| { p.y }).max().unwrap();
+    // leave room for sand falling diagonally past the left-most and right-most rocks and the source
+    let left = min(min_x, SOURCE.x).saturating_sub(1);
+    let (rows, columns) = (max_y.saturating_add(1), max_x.max(SOURCE.x).saturating_add(2) - left);
+    if rows.checked_mul(columns).is_none_or(|size| size > MAX_MAP_SIZE) {
+        return Err(Error::new("", format!("the rock paths span more than {MAX_MAP_SIZE} positions")));
+    }
+    let mut map = Map { map: Grid::new(rows, columns, Material::Air), left, lowest_rock: max_y };
+    for (from, to) in segments {
+        for x in min(from.x, to.x)..=max(from.x, to.x) {
+            for y in min(from.y, to.y)..=max(from.y, to.y) {
+                map.set(Point::new(x, y), Material::Rock);
+            }
+        }
+    }
+    Ok(map)
+}
+
+pub fn part1(input: &str) -> Result<u32> {
+    let mut map = parse_scan(input)?;
+    Ok(map.sand_until_full())
+}
+
+/// Drops sand a few units at a time to watch where it comes to rest.
+struct Explorer {
+    map: Map,
+    at_rest: u32,
+}
+
+impl Session for Explorer {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[("drop [N]", "drop N units of sand, 1 by default"), ("show", "the rocks and the sand at rest")]
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> std::result::Result<String, String> {
+        if command == "show" {
+            return Ok(self.map.to_string());
+        }
+        let mut falls_out = false;
+        for _ in 0..argument(args, "N", Some(1))? {
+            if !self.map.drop_sand() {
+                falls_out = true;
+                break;
+            }
+            self.at_rest += 1;
+        }
+        let units = if self.at_rest == 1 { "unit" } else { "units" };
+        let at_rest = format!("{} {units} of sand at rest", self.at_rest);
+        Ok(if falls_out { format!("sand falls out at the bottom, {at_rest}") } else { at_rest })
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(parse_scan(input).map(drop))
+    }
+
+    fn explore(&self, input: &str) -> Option<Result<Box<dyn Session>>> {
+        Some(parse_scan(input).map(|map| Box::new(Explorer { map, at_rest: 0 }) as Box<dyn Session>))
+    }
+}
@@ -0,0 +1,180 @@
+use crate::y2022::day2::Symbol::{Rock, Paper, Scissors};
+use crate::y2022::day2::Outcome::*;
+use crate::error::Result;
+use crate::parse::{self, Scanner};
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use crate::y2022::day2::Symbol::*;
+
+    const SAMPLE_INPUT: &str = indoc! {"A Y
+        B X
+        C Z
+    "};
+
+    #[test]
+    fn parses_rock_rock() {
+        assert_eq!(super::parse("A X"), Ok((Rock, Rock)))
+    }
+
+    #[test]
+    fn parses_paper_paper() {
+        assert_eq!(super::parse("B Y"), Ok((Paper, Paper)))
+    }
+
+    #[test]
+    fn parses_scissors_scissors() {
+        assert_eq!(super::parse("C Z"), Ok((Scissors, Scissors)))
+    }
+
+    #[test]
+    fn reports_unknown_symbols() {
+        let error = super::total_score("A X\nB Q\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 3, "Q"));
+        let error = super::total_score2("D X\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 1, "D"));
+        let error = super::parse("A").unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (2, "missing my symbol"));
+    }
+    #[test]
+    fn computes_total_score() {
+        assert_eq!(super::total_score(SAMPLE_INPUT), Ok(15))
+    }
+    #[test]
+    fn computes_total_score2() {
+        assert_eq!(super::total_score2(SAMPLE_INPUT), Ok(12))
+    }
+}
+
+pub fn total_score(input: &str) -> Result<u32> {
+    let mut sum = 0;
+    for (line_idx, line) in input.lines().enumerate() {
+        let (opponent, mine) = parse(line).map_err(|e| e.on_line(line_idx + 1))?;
+        sum += shape_score(&mine);
+        sum += match_score(&opponent, &mine);
+    }
+    Ok(sum)
+}
+pub fn total_score2(input: &str) -> Result<u32> {
+    let mut sum = 0;
+    for (line_idx, line) in input.lines().enumerate() {
+        let (opponent, outcome) = parse2(line).map_err(|e| e.on_line(line_idx + 1))?;
+        sum += shape_score2(&opponent,&outcome);
+        sum += match_score2(&outcome);
+    }
+    Ok(sum)
+}
+
+fn match_score2(outcome: &Outcome) -> u32 {
+    match outcome {
+        Lose => 0,
+        Draw => 3,
+        Win => 6,
+    }
+}
+
+fn match_score(opponent: &Symbol, mine: &Symbol) -> u32 {
+    if opponent == mine {
+        return 3
+    }
+    match (opponent, mine) {
+        (Rock, Paper) => 6,
+        (Paper, Rock) => 0,
+        (Rock, Scissors) => 0,
+        (Scissors, Rock) => 6,
+        (Paper, Scissors) => 6,
+        (Scissors, Paper) => 0,
+        _ => panic!()
+    }
+}
+
+fn shape_score(symbol: &Symbol) -> u32 {
+    match symbol {
+        Rock => 1,
+        Paper => 2,
+        Scissors => 3,
+    }
+}
+
+fn shape_score2(opponent:&Symbol, outcome: &Outcome) -> u32 {
+    if outcome == &Draw {
+        return shape_score(opponent);
+    }
+    match (opponent, outcome) {
+        (Rock, Lose) => shape_score(&Scissors),
+        (Rock, Win) => shape_score(&Paper),
+        (Paper, Lose) => shape_score(&Rock),
+        (Paper, Win) => shape_score(&Scissors),
+        (Scissors, Lose) => shape_score(&Paper),
+        (Scissors, Win) => shape_score(&Rock),
+        _ => panic!()
+    }
+}
+
+pub fn parse(line: &str) -> Result<(Symbol, Symbol)> {
+    parse::line(line, |s| {
+        let opponent = parse_opponent(s)?;
+        let mine = match s.word() {
+            "X" => Rock,
+            "Y" => Paper,
+            "Z" => Scissors,
+            "" => return Err(s.error(s.rest(), "missing my symbol")),
+            value => return Err(s.error(value, "could not match my symbol")),
+        };
+        Ok((opponent, mine))
+    })
+}
+
+pub fn parse2(line: &str) -> Result<(Symbol, Outcome)> {
+    parse::line(line, |s| {
+        let opponent = parse_opponent(s)?;
+        let outcome = match s.word() {
+            "X" => Lose,
+            "Y" => Draw,
+            "Z" => Win,
+            "" => return Err(s.error(s.rest(), "missing outcome")),
+            value => return Err(s.error(value, "could not match outcome")),
+        };
+        Ok((opponent, outcome))
+    })
+}
+
+fn parse_opponent(s: &mut Scanner) -> Result<Symbol> {
+    match s.word() {
+        "A" => Ok(Rock),
+        "B" => Ok(Paper),
+        "C" => Ok(Scissors),
+        "" => Err(s.error(s.line(), "missing opponent's symbol")),
+        value => Err(s.error(value, "could not match opponent's symbol")),
+    }
+}
+
+#[derive(PartialEq)]
+#[derive(Debug)]
+pub enum Symbol {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(PartialEq)]
+#[derive(Debug)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(total_score(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        Some(total_score2(input).map(Answer::from))
+    }
+}
@@ -0,0 +1,145 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use itertools::Itertools;
+
+    const SAMPLE_INPUT: &str = indoc! {"vJrwpWtwJgWrhcsFMMfFFhFp
+        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+        PmmdzqPrVvPwwTWBwg
+        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+        ttgJtRGJQctTZtZT
+        CrZsJsPPZsGzwwsLwLmpwMDw
+    "};
+
+    #[test]
+    fn parses_single_line() {
+        assert_eq!(super::priority_sum(SAMPLE_INPUT.lines().next().unwrap()), Ok(16))
+    }
+    #[test]
+    fn parses_sample_input() {
+        assert_eq!(super::priority_sum(SAMPLE_INPUT), Ok(157))
+    }
+
+    #[test]
+    fn computes_badge_priority_sum() {
+        assert_eq!(super::badge_priority_sum(SAMPLE_INPUT), Ok(70))
+    }
+
+    #[test]
+    fn reports_rucksack_without_shared_item() {
+        let error = super::priority_sum("abcb\nabcA\n").unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (2, "abcA"));
+    }
+
+    #[test]
+    fn reports_non_letter_items() {
+        let error = super::priority_sum("ab1d1x\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 3, "1"));
+    }
+
+    #[test]
+    fn reports_incomplete_group() {
+        let error = super::badge_priority_sum(&SAMPLE_INPUT.lines().take(4).join("\n")).unwrap_err();
+        assert_eq!(error.line, 4);
+        assert_eq!(error.message, "expected a group of three rucksacks");
+    }
+
+    #[test]
+    fn validates_every_rucksack_and_group() {
+        assert_eq!(super::validate(SAMPLE_INPUT), vec![]);
+        let violations = super::validate("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZS1\nPmmdzqPrVvPwwTWBwg\nwMqvLMZHhH\n");
+        let found: Vec<_> = violations.iter().map(|e| (e.line, e.column, e.message.as_str())).collect();
+        assert_eq!(found, vec![
+            (2, 1, "expected two compartments of the same size"),
+            (2, 25, "expected an item between a and z or A and Z"),
+            (4, 1, "expected a group of three rucksacks, the last group has 1"),
+        ]);
+    }
+}
+
+pub fn priority_sum(input: &str) -> Result<u32> {
+    let mut sum = 0;
+    for (line_idx, line) in input.lines().enumerate() {
+        let priority_char = find_priority_char(line).map_err(|e| e.on_line(line_idx + 1))?;
+        sum += char_to_priority(line, priority_char).map_err(|e| e.on_line(line_idx + 1))?;
+    }
+    Ok(sum)
+}
+
+/// The priority of the item `c`, which has to be a slice of `line` for error reporting.
+pub fn char_to_priority(line: &str, c: &str) -> Result<u32> {
+    match c.chars().next() {
+        Some(c) if c.is_ascii_lowercase() => Ok(c as u32 - 'a' as u32 + 1),
+        Some(c) if c.is_ascii_uppercase() => Ok(c as u32 - 'A' as u32 + 27),
+        _ => Err(Error::in_line(line, c, "expected an item between a and z or A and Z")),
+    }
+}
+
+fn find_priority_char(line: &str) -> Result<&str> {
+    if !line.is_char_boundary(line.len() / 2) {
+        return Err(Error::in_line(line, line, "expected two compartments of the same size"));
+    }
+    let (left, right) = line.split_at(line.len() / 2);
+    for (idx, left_char) in left.char_indices() {
+        if right.contains(left_char) {
+            return Ok(&left[idx..idx + left_char.len_utf8()]);
+        }
+    }
+    Err(Error::in_line(line, line, "no item is in both compartments"))
+}
+
+pub fn badge_priority_sum(input: &str) -> Result<u32> {
+    let mut sum = 0;
+    let mut lines = input.lines().enumerate();
+    while let Some((line_idx, first_line)) = lines.next() {
+        let (Some((_, second_line)), Some((_, third_line))) = (lines.next(), lines.next()) else {
+            return Err(Error::new(first_line, "expected a group of three rucksacks").on_line(line_idx + 1));
+        };
+        let badge = first_line.char_indices()
+            .find(|&(_, c)| second_line.contains(c) && third_line.contains(c))
+            .map(|(idx, c)| &first_line[idx..idx + c.len_utf8()])
+            .ok_or_else(|| Error::new(first_line, "no item is carried by all three elves of the group").on_line(line_idx + 1))?;
+        sum += char_to_priority(first_line, badge).map_err(|e| e.on_line(line_idx + 1))?;
+    }
+    Ok(sum)
+}
+
+/// Every rucksack whose items cannot be split into two compartments, and an incomplete last group.
+pub fn validate(input: &str) -> Vec<Error> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut violations = Vec::new();
+    for (line_idx, line) in lines.iter().enumerate() {
+        if !line.chars().count().is_multiple_of(2) {
+            violations.push(Error::in_line(line, line, "expected two compartments of the same size").on_line(line_idx + 1));
+        }
+        for (byte_idx, c) in line.char_indices().filter(|(_, c)| !c.is_ascii_alphabetic()) {
+            let item = &line[byte_idx..byte_idx + c.len_utf8()];
+            violations.push(Error::in_line(line, item, "expected an item between a and z or A and Z").on_line(line_idx + 1));
+        }
+    }
+    if !lines.len().is_multiple_of(3) {
+        let first = lines.len() - lines.len() % 3;
+        let message = format!("expected a group of three rucksacks, the last group has {}", lines.len() % 3);
+        violations.push(Error::new(lines[first], message).on_line(first + 1));
+    }
+    violations
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(priority_sum(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        Some(badge_priority_sum(input).map(Answer::from))
+    }
+
+    fn validate(&self, input: &str) -> Option<Vec<Error>> {
+        Some(validate(input))
+    }
+}
@@ -0,0 +1,102 @@
+use std::ops::RangeInclusive;
+use crate::error::Result;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use proptest::prelude::*;
+
+    const SAMPLE_INPUT: &str = indoc! {"2-4,6-8
+        2-3,4-5
+        5-7,7-9
+        2-8,3-7
+        6-6,4-6
+        2-6,4-8
+    "};
+
+    #[test]
+    fn finds_fully_contained_pairs() {
+        assert_eq!(super::fully_contained_pairs(SAMPLE_INPUT), Ok(2))
+    }
+
+    #[test]
+    fn finds_overlapping_pairs() {
+        assert_eq!(super::overlapping_pairs(SAMPLE_INPUT), Ok(4))
+    }
+
+    #[test]
+    fn reports_invalid_sections() {
+        let error = super::overlapping_pairs("2-4,6-8\n2-3;4-5\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 4, ";"));
+        let error = super::overlapping_pairs("2-4,6-x\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 7, "x"));
+        let error = super::overlapping_pairs("2-4,68\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 5, "68"));
+    }
+
+    fn range() -> impl Strategy<Value=std::ops::RangeInclusive<u32>> {
+        (0..100_u32, 0..20_u32).prop_map(|(start, len)| start..=start + len)
+    }
+
+    proptest! {
+        #[test]
+        fn containment_implies_overlap(left in range(), right in range()) {
+            if super::is_fully_contained(&left, &right) {
+                prop_assert!(super::overlaps(&left, &right));
+            }
+            prop_assert_eq!(super::overlaps(&left, &right), super::overlaps(&right, &left));
+            prop_assert_eq!(super::overlaps(&left, &right), left.clone().any(|section| right.contains(&section)));
+        }
+    }
+}
+
+pub fn fully_contained_pairs(input: &str) -> Result<u32> {
+    map_count_assignments(input, is_fully_contained)
+}
+
+pub fn map_count_assignments(input: &str, predicate: fn(&RangeInclusive<u32>, &RangeInclusive<u32>) -> bool) -> Result<u32> {
+    let mut count = 0;
+    for (line_idx, line) in input.lines().enumerate() {
+        let (first, second) = parse_pair(line).map_err(|e| e.on_line(line_idx + 1))?;
+        if predicate(&first, &second) {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+pub fn overlapping_pairs(input: &str) -> Result<u32> {
+    map_count_assignments(input, overlaps)
+}
+
+pub fn overlaps(left_range: &RangeInclusive<u32>, right_range: &RangeInclusive<u32>) -> bool {
+    !(left_range.end() < right_range.start() || left_range.start() > right_range.end())
+}
+
+pub fn is_fully_contained(left_range: &RangeInclusive<u32>, right_range: &RangeInclusive<u32>) -> bool {
+    left_range.start() >= right_range.start() && left_range.end() <= right_range.end()
+        || left_range.start() <= right_range.start() && left_range.end() >= right_range.end()
+}
+
+/// The two comma separated section ranges of a pair of elves, like `2-4,6-8`.
+fn parse_pair(line: &str) -> Result<(RangeInclusive<u32>, RangeInclusive<u32>)> {
+    parse::line(line, |s| {
+        let first = s.range("a section")?;
+        s.expect(",")?;
+        Ok((first, s.range("a section")?))
+    })
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(fully_contained_pairs(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        Some(overlapping_pairs(input).map(Answer::from))
+    }
+}
@@ -0,0 +1,140 @@
+use crate::error::{Error, Result};
+use crate::parse::{self, Scanner};
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    const SAMPLE_INPUT: &str = indoc! {"    [D]
+        [N] [C]
+        [Z] [M] [P]
+         1   2   3
+
+        move 1 from 2 to 1
+        move 3 from 1 to 3
+        move 2 from 2 to 1
+        move 1 from 1 to 2
+    "};
+
+    #[test]
+    fn can_move_single_items() {
+        assert_eq!(super::crate_message(SAMPLE_INPUT), Ok("CMZ".to_string()))
+    }
+    #[test]
+    fn can_move_single_items2() {
+        assert_eq!(super::crate_message2(SAMPLE_INPUT), Ok("MCD".to_string()))
+    }
+
+    #[test]
+    fn reports_invalid_instructions() {
+        let error = super::crate_message(&SAMPLE_INPUT.replace("move 3 from 1 to 3", "move 3 from 1 to 4")).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (7, 18, "4"));
+        let error = super::crate_message(&SAMPLE_INPUT.replace("move 3 from 1 to 3", "move 4 from 1 to 3")).unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (7, "not enough crates on stack 1"));
+        let error = super::crate_message2(&SAMPLE_INPUT.replace("move 2 from 2 to 1", "move two from 2 to 1")).unwrap_err();
+        assert_eq!((error.line, error.column), (8, 6));
+        assert!(super::crate_message("    [D]\n 1\n").is_err());
+    }
+
+    #[test]
+    fn reports_malformed_crates() {
+        let error = super::crate_message("[Z] [M\n 1   2\nmove 1 from 1 to 2\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 5, "[M"));
+        let error = super::crate_message("[Ä]\n 1\nmove 1 from 1 to 1\n").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (1, "expected a crate like [A]"));
+    }
+}
+
+
+pub fn crate_message(input: &str) -> Result<String> {
+    rearrange(input, |stack, count| {
+        let mut moved = stack.split_off(stack.len() - count);
+        moved.reverse();
+        moved
+    })
+}
+
+pub fn crate_message2(input: &str) -> Result<String> {
+    rearrange(input, |stack, count| stack.split_off(stack.len() - count))
+}
+
+/// Runs all instructions, with `take` removing `count` crates from the top of a stack in the order
+/// they end up on the target stack, and returns the top crates.
+fn rearrange<'a>(input: &'a str, take: fn(&mut Vec<&'a str>, usize) -> Vec<&'a str>) -> Result<String> {
+    let mut blocks = parse::blocks(input);
+    let (first_line, raw_stacks) = blocks.next().ok_or_else(|| Error::new("", "no stacks of crates"))?;
+    let mut stacks = parse_stacks(raw_stacks).map_err(|e| e.in_block(first_line))?;
+    let mut instructions = blocks.flat_map(|(first_line, block)| block.lines().zip(first_line..)).peekable();
+    if instructions.peek().is_none() {
+        return Err(Error::new("", "no move instructions"));
+    }
+    for (instruction, line) in instructions {
+        let (count, from, to) = parse_instruction(instruction, stacks.len()).map_err(|e| e.on_line(line))?;
+        if count > stacks[from].len() {
+            return Err(Error::in_line(instruction, instruction, format!("not enough crates on stack {}", from + 1)).on_line(line));
+        }
+        let moved = take(&mut stacks[from], count);
+        stacks[to].extend(moved);
+    }
+    stacks.iter()
+        .enumerate()
+        .map(|(idx, stack)| stack.last().copied().ok_or_else(|| Error::new("", format!("stack {} ends up empty", idx + 1))))
+        .collect()
+}
+
+/// Parses `count`, `from` and `to` of a move, with zero-based stack indices checked against `stack_count`.
+pub fn parse_instruction(instruction: &str, stack_count: usize) -> Result<(usize, usize, usize)> {
+    parse::line(instruction, |s| {
+        s.expect("move ")?;
+        let count = s.unsigned("a number of crates")?;
+        s.expect(" from ")?;
+        let from = parse_stack(s, stack_count)?;
+        s.expect(" to ")?;
+        Ok((count, from, parse_stack(s, stack_count)?))
+    })
+}
+
+fn parse_stack(s: &mut Scanner, stack_count: usize) -> Result<usize> {
+    match s.consumed(|s| s.unsigned::<usize>("a stack number"))? {
+        (_, stack @ 1..) if stack <= stack_count => Ok(stack - 1),
+        (value, _) => Err(s.error(value, format!("no such stack, there are {stack_count}"))),
+    }
+}
+
+/// Parses the drawing of the stacks, bottom crate first, with crates like `[A]` four characters apart.
+pub fn parse_stacks(raw_stacks: &str) -> Result<Vec<Vec<&str>>> {
+    let stack_count = raw_stacks.lines().map(str::len).max().unwrap_or(0) / 4 + 1;
+    let mut stacks: Vec<Vec<&str>> = vec![Vec::new(); stack_count];
+    for (line_idx, layer) in raw_stacks.lines().enumerate().filter(|(_, l)| { !l.is_empty() && l.contains('[') }) {
+        let error = |part: &str| Error::in_line(layer, part, "expected a crate like [A]").on_line(line_idx + 1);
+        let stack_count = layer.len() / 4 + 1;
+        // dbg!(stack_count);
+        for (stack_idx, stack) in stacks.iter_mut().enumerate().take(stack_count) {
+            let start = stack_idx * 4;
+            // the last crate may be cut short, and a crate name may not be a single byte
+            let crate_str = layer.get(start..layer.len().min(start + 3)).ok_or_else(|| error(layer))?;
+            // dbg!(crate_str);
+            if crate_str.contains('[') {
+                if crate_str.len() != 3 || !crate_str.starts_with('[') || !crate_str.ends_with(']') {
+                    return Err(error(crate_str));
+                }
+                stack.insert(0, &crate_str[1..2]);
+            }
+        }
+        // dbg!(&stacks);
+    }
+    Ok(stacks)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(crate_message(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        Some(crate_message2(input).map(Answer::from))
+    }
+}
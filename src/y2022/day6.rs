@@ -0,0 +1,92 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
+    const SAMPLE_INPUT: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+
+    #[test]
+    fn test_start_packet_offset() {
+        assert_eq!(super::unique_characters_offset(SAMPLE_INPUT, 4), Ok(7))
+    }
+
+    #[test]
+    fn test_more_examples1() {
+        assert_eq!(super::unique_characters_offset("bvwbjplbgvbhsrlpgdmjqwftvncz", 4), Ok(5));
+    }
+
+    #[test]
+    fn test_more_examples2() {
+        assert_eq!(super::unique_characters_offset("nppdvjthqldpwncqszvftbrmjlhg", 4), Ok(6));
+    }
+
+    #[test]
+    fn test_more_examples3() {
+        assert_eq!(super::unique_characters_offset("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4), Ok(10));
+    }
+
+    #[test]
+    fn test_more_examples4() {
+        assert_eq!(super::unique_characters_offset("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4), Ok(11));
+    }
+
+    #[test]
+    fn reports_missing_marker() {
+        let error = super::unique_characters_offset("abcabc", 4).unwrap_err();
+        assert_eq!(error.message, "no marker of 4 distinct characters");
+    }
+
+    proptest! {
+        #[test]
+        fn finds_first_window_of_unique_characters(input in "[a-h]{0,40}", window in 1..=8_usize) {
+            let chars: Vec<char> = input.chars().collect();
+            let first_unique = chars.windows(window).position(|w| w.iter().all_unique());
+            match super::unique_characters_offset(&input, window) {
+                Ok(offset) => {
+                    prop_assert!(offset >= window);
+                    prop_assert!(chars[offset - window..offset].iter().all_unique());
+                    prop_assert_eq!(first_unique, Some(offset - window));
+                }
+                Err(_) => prop_assert_eq!(first_unique, None),
+            }
+        }
+    }
+}
+
+pub fn unique_characters_offset(input: &str, unique_chars: usize) -> Result<usize> {
+    input.chars().enumerate().scan(Vec::with_capacity(unique_chars), |state, (idx, c)| {
+        // dbg!(&c, &state);
+
+        if let Some(pos) = state.iter().position(|&ch| { ch == c }) {
+            // *state = state.split_off(pos + 1)
+            state.drain(..pos + 1);
+        }
+        state.push(c);
+        // dbg!(&state);
+        if state.len() == unique_chars {
+            Some(idx + 1)
+        } else {
+            Some(0)
+        }
+    // }).inspect(|i| {
+        // dbg!(i);
+    }).find(|&x| {
+        x > 0
+    }).ok_or_else(|| Error::new("", format!("no marker of {unique_chars} distinct characters")))
+    // 0
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(unique_characters_offset(input, 4)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        Some(unique_characters_offset(input, 14).map(Answer::from))
+    }
+}
@@ -0,0 +1,207 @@
+use std::cmp::max;
+use crate::error::{Error, Result};
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    const SAMPLE_INPUT: &str = indoc! {"$ cd /
+        $ ls
+        dir a
+        14848514 b.txt
+        8504156 c.dat
+        dir d
+        $ cd a
+        $ ls
+        dir e
+        29116 f
+        2557 g
+        62596 h.lst
+        $ cd e
+        $ ls
+        584 i
+        $ cd ..
+        $ cd ..
+        $ cd d
+        $ ls
+        4060174 j
+        8033020 d.log
+        5626152 d.ext
+        7214296 k"};
+
+    #[test]
+    fn test_sample_input() {
+        assert_eq!(super::size_of_small_dirs(SAMPLE_INPUT), Ok(95437))
+    }
+
+    #[test]
+    fn smallest_directory_to_delete() {
+        assert_eq!(super::smallest_directory_to_delete(SAMPLE_INPUT), Ok(24933642));
+    }
+
+    #[test]
+    fn reports_invalid_log_lines() {
+        let error = super::size_of_small_dirs(&SAMPLE_INPUT.replace("29116 f", "29k16 f")).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (10, 1, "29k16"));
+        let error = super::size_of_small_dirs("$ cd a\n").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (1, "changed into a directory before changing into /"));
+        let error = super::size_of_small_dirs("$ cd /\n$ cd\n").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (2, "expected a directory to change into"));
+    }
+
+    #[test]
+    fn adds_up_more_than_u32_bytes() {
+        let input = "$ cd /\n$ ls\n4000000000 a\n4000000000 b\n";
+        let dirs = super::parse_console_log(input).unwrap();
+        assert_eq!(dirs[0].size(&dirs), 8_000_000_000);
+        let error = super::smallest_directory_to_delete(input).unwrap_err();
+        assert_eq!(error.message, "the files do not fit on the file system");
+    }
+}
+
+pub fn size_of_small_dirs(input: &str) -> Result<u64> {
+    let dirs = parse_console_log(input)?;
+    Ok(dirs.iter()
+        // .inspect(|d| { dbg!(d); })
+        .map(|dir| {
+            dir.size(&dirs)
+        })
+        // .inspect(|size| { dbg!(size); })
+        .filter(|&size| { size < 100_000 })
+        .sum())
+}
+
+#[derive(Debug)]
+pub struct DirTree {
+    pub name: String,
+    directories: Vec<String>,
+    files: Vec<u32>,
+}
+
+impl DirTree {
+    pub fn new_sub_directory(&mut self, name: String) -> DirTree {
+        let dir_name = if self.name != "/" {
+            self.name.clone() + "/" + &name
+        } else {
+            self.name.clone() + &name
+        };
+        self.directories.push(dir_name.clone());
+        DirTree {
+            name: dir_name,
+            directories: vec![],
+            files: vec![],
+        }
+    }
+
+    pub fn add_file(&mut self, size: u32) {
+        self.files.push(size);
+    }
+
+    /// The size of the files in the directory and all its subdirectories, summed up without overflowing.
+    pub fn size(&self, dirs: &[DirTree]) -> u64 {
+        dirs.iter()
+            .filter(|d| { d.name.starts_with(&self.name) })
+            .map(|d| { d.file_size() }).sum()
+    }
+    pub fn file_size(&self) -> u64 {
+        self.files.iter().copied().map(u64::from).sum()
+    }
+}
+
+/// A line of the console log.
+enum LogLine<'a> {
+    Cd(&'a str),
+    Ls,
+    Dir,
+    File(u32),
+}
+
+fn parse_log_line(line: &str) -> Result<LogLine<'_>> {
+    parse::line(line, |s| {
+        if s.accept("$ cd") {
+            if !s.accept(" ") {
+                return Err(s.error(line, "expected a directory to change into"));
+            }
+            Ok(LogLine::Cd(s.take_rest()))
+        } else if s.accept("$ ls") {
+            Ok(LogLine::Ls)
+        } else if s.accept("dir ") {
+            s.take_rest();
+            Ok(LogLine::Dir)
+        } else {
+            let size = s.unsigned("a file size")?;
+            s.expect(" ")?;
+            s.take_rest();
+            Ok(LogLine::File(size))
+        }
+    })
+}
+
+pub fn parse_console_log(input: &str) -> Result<Vec<DirTree>> {
+    let mut dirs: Vec<DirTree> = Vec::new();
+    let mut cwd = String::new();
+    for (line_idx, line) in input.lines().enumerate() {
+        let error = |part: &str, message: &str| Error::in_line(line, part, message).on_line(line_idx + 1);
+        let cwd_idx = |dirs: &[DirTree], cwd: &str| dirs.iter().position(|d| { d.name == cwd })
+            .ok_or_else(|| error(line, "changed into a directory before changing into /"));
+        match parse_log_line(line).map_err(|e| e.on_line(line_idx + 1))? {
+            LogLine::Cd(cd @ "..") => {
+                let parent = cwd.rfind('/').ok_or_else(|| error(cd, "changed to the parent directory before changing into /"))?;
+                cwd.truncate(max(1, parent));
+            }
+            LogLine::Cd("/") => {
+                dirs.push(DirTree {
+                    name: "/".to_string(),
+                    directories: vec![],
+                    files: vec![],
+                });
+                cwd = String::from("/");
+            }
+            LogLine::Cd(cd) => {
+                let idx = cwd_idx(&dirs, &cwd)?;
+                let sub_dir = dirs[idx].new_sub_directory(cd.to_string());
+                cwd = sub_dir.name.clone();
+                dirs.push(sub_dir);
+            }
+            LogLine::Ls | LogLine::Dir => {}
+            LogLine::File(size) => {
+                let idx = cwd_idx(&dirs, &cwd)?;
+                dirs[idx].add_file(size);
+            }
+        }
+    }
+    Ok(dirs)
+}
+
+static FS_SPACE: u64 = 70_000_000;
+static REQUIRED_SPACE: u64 = 30_000_000;
+pub fn smallest_directory_to_delete(input: &str) -> Result<u64> {
+    let dirs = parse_console_log(input)?;
+    let root = dirs.first().ok_or_else(|| Error::new("", "no directories in the console log"))?;
+    let free_space = FS_SPACE.checked_sub(root.size(&dirs))
+        .ok_or_else(|| Error::new("", "the files do not fit on the file system"))?;
+    let space_to_free = REQUIRED_SPACE.saturating_sub(free_space);
+    dirs.iter()
+        .map(|d| { d.size(&dirs)})
+        .filter(|&d| { d > space_to_free })
+        .min()
+        .ok_or_else(|| Error::new("", "no directory frees enough space"))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(size_of_small_dirs(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        Some(smallest_directory_to_delete(input).map(Answer::from))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(parse_console_log(input).map(drop))
+    }
+}
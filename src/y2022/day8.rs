@@ -0,0 +1,149 @@
+use crate::error::{Error, Result};
+use crate::geom::{Direction, Point};
+use crate::grid::Grid;
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    const SAMPLE_INPUT: &str = indoc! {"30373
+        25512
+        65332
+        33549
+        35390"};
+
+
+    const MIN_INPUT: &str = indoc! {"303
+        255
+        653"};
+
+    #[test]
+    fn test_visible_trees_min() {
+        assert_eq!(super::count_visible_trees(MIN_INPUT), Ok(9));
+    }
+
+    #[test]
+    fn test_visible_trees() {
+        assert_eq!(super::count_visible_trees(SAMPLE_INPUT), Ok(21));
+    }
+
+    #[test]
+    fn test_scenic_score() {
+        assert_eq!(super::highest_scenic_score(SAMPLE_INPUT), Ok(8));
+    }
+
+    #[test]
+    fn scores_views_beyond_u32() {
+        let mut rows = vec!["0".repeat(600); 600];
+        rows[300].replace_range(300..301, "9");
+        assert_eq!(super::highest_scenic_score(&rows.join("\n")), Ok(300 * 300 * 299 * 299));
+    }
+
+    #[test]
+    fn reports_invalid_trees() {
+        let error = super::count_visible_trees("303\n2a5\n653").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "a"));
+        let error = super::highest_scenic_score("303\n25\n653").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (2, "expected 3 cells like in the first row"));
+        assert!(super::count_visible_trees("").is_err());
+    }
+
+    #[test]
+    fn solves_rectangular_grids() {
+        assert_eq!(super::count_visible_trees("30373\n25512\n65332"), Ok(14));
+        assert_eq!(super::highest_scenic_score("303\n255\n653\n335\n353"), Ok(2));
+        assert_eq!(super::validate("30373\n25512\n65332"), vec![]);
+    }
+
+    #[test]
+    fn validates_rows_and_tree_heights() {
+        assert_eq!(super::validate(SAMPLE_INPUT), vec![]);
+        let violations = super::validate("303\n2a5x\n65");
+        let found: Vec<_> = violations.iter().map(|e| (e.line, e.column, e.message.as_str())).collect();
+        assert_eq!(found, vec![
+            (2, 1, "expected 3 trees like in the first row, found 4"),
+            (2, 2, "expected a tree height"),
+            (2, 4, "expected a tree height"),
+            (3, 1, "expected 3 trees like in the first row, found 2"),
+        ]);
+        assert_eq!(super::validate("")[0].message, "no trees");
+    }
+}
+
+pub fn count_visible_trees(input: &str) -> Result<u32> {
+    let trees = parse_trees(input)?;
+    Ok(trees.points().filter(|&position| visible(&trees, position)).count() as u32)
+}
+
+/// Parses the grid of tree heights.
+pub fn parse_trees(input: &str) -> Result<Grid<u32>> {
+    let trees = Grid::parse(input, |_, chr| chr.to_digit(10).ok_or_else(|| "expected a tree height".to_string()))?;
+    if trees.dimensions().1 == 0 {
+        return Err(Error::new("", "no trees"));
+    }
+    Ok(trees)
+}
+
+/// Whether all trees between the tree at `position` and an edge of the grid are smaller.
+fn visible(trees: &Grid<u32>, position: Point<usize>) -> bool {
+    Direction::ORTHOGONAL.into_iter().any(|direction| trees.ray(position, direction).all(|&tree| tree < trees[position]))
+}
+
+pub fn highest_scenic_score(input: &str) -> Result<u64> {
+    let trees = parse_trees(input)?;
+    Ok(trees.points().map(|position| compute_scenic_score(&trees, position)).max().unwrap_or(0))
+}
+
+fn compute_scenic_score(trees: &Grid<u32>, position: Point<usize>) -> u64 {
+    Direction::ORTHOGONAL.into_iter().map(|direction| {
+        // up to and including the first tree that is at least as high
+        let mut viewing_distance = 0_usize;
+        for &tree in trees.ray(position, direction) {
+            viewing_distance += 1;
+            if tree >= trees[position] {
+                break;
+            }
+        }
+        viewing_distance as u64
+    }).product()
+}
+
+/// Every row keeping the input from being a grid of tree heights.
+pub fn validate(input: &str) -> Vec<Error> {
+    let Some(size) = input.lines().next().map(|line| line.chars().count()) else {
+        return vec![Error::new("", "no trees")];
+    };
+    let mut violations = Vec::new();
+    for (line_idx, line) in input.lines().enumerate() {
+        let error = |part: &str, message: String| Error::in_line(line, part, message).on_line(line_idx + 1);
+        let trees = line.chars().count();
+        if trees != size {
+            violations.push(error(line, format!("expected {size} trees like in the first row, found {trees}")));
+        }
+        for (byte_idx, chr) in line.char_indices().filter(|(_, chr)| !chr.is_ascii_digit()) {
+            violations.push(error(&line[byte_idx..byte_idx + chr.len_utf8()], "expected a tree height".to_string()));
+        }
+    }
+    violations
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(count_visible_trees(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        Some(highest_scenic_score(input).map(Answer::from))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(parse_trees(input).map(drop))
+    }
+
+    fn validate(&self, input: &str) -> Option<Vec<Error>> {
+        Some(validate(input))
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+
+use crate::error::Result;
+use crate::geom::{Direction, Point};
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const SAMPLE_INPUT: &str = indoc! {"R 4
+        U 4
+        L 3
+        D 1
+        R 4
+        D 1
+        L 5
+        R 2 "};
+
+    #[test]
+    fn part1_counts_start() {
+        assert_eq!(part1(""), Ok(1));
+    }
+
+    #[test]
+    fn part1_moves_right() {
+        assert_eq!(part1("R 3"), Ok(3));
+    }
+
+    #[test]
+    fn part1_moves_up() {
+        assert_eq!(part1("U 3"), Ok(3));
+    }
+
+    #[test]
+    fn part1_moves_down() {
+        assert_eq!(part1("D 3"), Ok(3));
+    }
+
+    #[test]
+    fn part1_moves_left() {
+        assert_eq!(part1("L 3"), Ok(3));
+    }
+
+    #[test]
+    fn part1_moves_diagonal_up_right() {
+        let s = SAMPLE_INPUT.lines().take(2).fold(String::new(), |a, b| a + b + "\n");
+        assert_eq!(part1(&s), Ok(7));
+    }
+
+    #[test]
+    fn part1_moves_diagonal_up_left() {
+        let s = SAMPLE_INPUT.lines().take(3).fold(String::new(), |a, b| a + b + "\n");
+        assert_eq!(part1(&s), Ok(9));
+    }
+
+    #[test]
+    fn part1_moves_diagonal_down_right() {
+        let s = SAMPLE_INPUT.lines().take(5).fold(String::new(), |a, b| a + b + "\n");
+        assert_eq!(part1(&s), Ok(10));
+    }
+
+    #[test]
+    fn part1_moves_diagonal_left_down() {
+        let s = SAMPLE_INPUT.lines().take(7).fold(String::new(), |a, b| a + b + "\n");
+        assert_eq!(part1(&s), Ok(13));
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(SAMPLE_INPUT), Ok(13));
+    }
+
+
+    const PART2_SAMPLE: &str = indoc! {"R 5
+        U 8
+        L 8
+        D 3
+        R 17
+        D 10
+        L 25
+        U 20"};
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(PART2_SAMPLE), Ok(36));
+    }
+
+    #[test]
+    fn reports_invalid_motions() {
+        let error = part1("R 4\nX 4\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 1, "X"));
+        let error = part2("R 4\n\nU four\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (3, 3, "four"));
+        let error = part1("R 4\nü\n").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (2, "expected a direction and a number of steps"));
+    }
+
+    proptest! {
+        #[test]
+        fn knots_stay_adjacent(knot_count in 2..=10_usize, steps in prop::collection::vec(0..4_usize, 0..200)) {
+            let mut knots = vec![Point::default(); knot_count];
+            for direction in steps {
+                step(&mut knots, Direction::ORTHOGONAL[direction]);
+                for (head, tail) in knots.iter().zip(&knots[1..]) {
+                    prop_assert!(head.chebyshev(*tail) <= 1, "{:?}", knots);
+                }
+            }
+        }
+    }
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    tail_visits(input, &mut [Point::default(); 10])
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    tail_visits(input, &mut [Point::default(); 2])
+}
+
+/// The number of positions the last of the `knots` visits while the first one follows the motions.
+fn tail_visits(input: &str, knots: &mut [Point<i32>]) -> Result<usize> {
+    let mut visited = HashSet::new();
+    visited.insert(*knots.last().unwrap());
+    for (line_idx, instruction) in input
+        .lines()
+        .map(str::trim)
+        .enumerate()
+        .filter(|(_, l)| { !l.is_empty() }) {
+        let (direction, steps) = parse_motion(instruction).map_err(|e| e.on_line(line_idx + 1))?;
+        for _ in 0..steps {
+            step(knots, direction);
+            visited.insert(*knots.last().unwrap());
+        }
+    }
+    Ok(visited.len())
+}
+
+/// Moves the head one step into `direction`, every other knot following the one before it.
+fn step(knots: &mut [Point<i32>], direction: Direction) {
+    knots[0] += direction.offset();
+    for i in 1..knots.len() {
+        knots[i] = new_knot_position(knots[i - 1], knots[i]);
+    }
+}
+
+/// The direction and the number of steps of a motion like `R 4`.
+fn parse_motion(instruction: &str) -> Result<(Direction, usize)> {
+    parse::line(instruction, |s| {
+        let direction_str = s.word();
+        if !s.accept(" ") {
+            return Err(s.error(instruction, "expected a direction and a number of steps"));
+        }
+        let direction = direction_str.parse::<Direction>().map_err(|message| s.error(direction_str, message))?;
+        Ok((direction, s.unsigned("a number of steps")?))
+    })
+}
+
+/// Where `tail` moves to follow `head`: nowhere while they touch, otherwise one step straight or
+/// diagonally towards it.
+fn new_knot_position(head: Point<i32>, tail: Point<i32>) -> Point<i32> {
+    if head.chebyshev(tail) <= 1 {
+        return tail;
+    }
+    let distance = head - tail;
+    tail + Point::new(distance.x.signum(), distance.y.signum())
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Result<Answer>> {
+        Some(part2(input).map(Answer::from))
+    }
+}
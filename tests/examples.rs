@@ -0,0 +1,42 @@
+//! Solves the puzzle samples in `tests/data/y<YEAR>/day<N>/sample<K>.txt` and compares the answers
+//! with the ones in `sample<K>.expected`, for every registered year and day. The samples are found
+//! when the test runs, so adding a sample only takes adding its two files.
+
+use std::fs;
+
+use adventofrust::input::{default_samples_dir, read_samples};
+use adventofrust::verify::{compare, parse_sample_answers};
+use adventofrust::YEARS;
+
+#[test]
+fn solves_every_sample() {
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for &(year, days) in YEARS {
+        let dir = default_samples_dir(year);
+        for &(day, solution) in days {
+            let samples = read_samples(&dir, day).unwrap_or_else(|e| panic!("could not read the samples of {year} day {day}: {e}"));
+            for (sample, input) in samples {
+                // samples without answers are only there to be run by hand
+                let Ok(expected) = fs::read_to_string(dir.join(format!("day{day}")).join(format!("{sample}.expected"))) else {
+                    continue;
+                };
+                let expected = parse_sample_answers(&expected).unwrap_or_else(|e| panic!("{year} day{day}/{sample}.expected: {e}"));
+                for (part, expected) in expected {
+                    checked += 1;
+                    let failure = match solution.solve(part, &input) {
+                        None => format!("has no part {part}"),
+                        Some(Err(error)) => error.for_day(day).to_string(),
+                        Some(Ok(answer)) => match compare(&expected, &answer) {
+                            None => continue,
+                            Some(difference) => difference,
+                        },
+                    };
+                    failures.push(format!("{year} day {day} part {part} on {sample}: {failure}"));
+                }
+            }
+        }
+    }
+    assert!(checked > 0, "found no samples with answers");
+    assert!(failures.is_empty(), "{} of {checked} sample answers are wrong:\n{}", failures.len(), failures.join("\n"));
+}
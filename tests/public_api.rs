@@ -0,0 +1,40 @@
+use adventofrust::y2022::day10::CathodeRay;
+use adventofrust::y2022::day13::Packet;
+use adventofrust::y2022::{day1, DAYS};
+use adventofrust::{days, latest_year, Answer};
+
+#[test]
+fn solves_through_the_registry() {
+    let (day, solution) = DAYS[0];
+    assert_eq!(day, 1);
+    assert_eq!(solution.part1("1000\n2000\n\n4000\n"), Ok(Answer::Number(4000)));
+    assert_eq!(solution.part2("1000\n2000\n\n4000\n"), Some(Ok(Answer::Number(7000))));
+}
+
+#[test]
+fn looks_up_days_by_year() {
+    assert_eq!(latest_year(), 2022);
+    assert_eq!(days(2022).map(<[_]>::len), Some(DAYS.len()));
+    assert!(days(2014).is_none());
+}
+
+#[test]
+fn streams_only_days_without_validator() {
+    for &(day, solution) in DAYS {
+        assert!(!solution.streams() || solution.validate("").is_none(), "day {day} streams its input past its validator");
+    }
+}
+
+#[test]
+fn reports_malformed_input_with_its_location() {
+    let error = DAYS[0].1.part1("1000\nlots\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.for_day(1).to_string(), "day 1, line 2, column 1: expected a number of calories: \"lots\"");
+}
+
+#[test]
+fn exposes_day_functions_and_types() {
+    assert_eq!(day1::most_calories("1\n2\n\n4\n"), Ok(4));
+    assert!(Packet::new("[1,[2]]").unwrap() < Packet::new("[[1],3]").unwrap());
+    assert_eq!(CathodeRay::new("noop\naddx 3\n").unwrap().register_x_during_cycle(4), 4);
+}